@@ -1,16 +1,21 @@
 use agent_core::{Message, Result};
-use executor::Executor;
+use config::AgentConfig;
+use executor::{ExecutionResult, Executor};
 use guardrails::GuardrailRegistry;
-use memory::MemoryStore;
+use memory::{ContextStrategy, MemoryStore};
 use planner::Planner;
 use tools::ToolRegistry;
 
+mod readiness;
+
+pub use readiness::{check_readiness, CheckStatus, ReadinessCheck, ReadinessReport};
+
 /// A high-level AI agent that orchestrates planning, execution, and memory.
 pub struct Agent {
-    memory: Box<dyn MemoryStore>,
     planner: Planner,
     executor: Executor,
     guardrails: GuardrailRegistry,
+    config: Option<AgentConfig>,
 }
 
 impl Agent {
@@ -21,19 +26,35 @@ impl Agent {
 
     /// Execute a task using the agent's workflow.
     ///
-    /// 1. Store user task in memory
-    /// 2. Generate a plan using the Planner
+    /// 1. Generate a plan using the Planner (with context from earlier turns)
+    /// 2. Store user task in memory
     /// 3. Validate the plan using Guardrails
     /// 4. Execute the plan using the Executor
     /// 5. Store the result in memory
+    ///
+    /// The conversation is stored in the Planner's memory, so its
+    /// `ContextStrategy` selects from the turns recorded here.
+    ///
+    /// Returns only the final response; use `run_with_result` to also get
+    /// the step results and any non-fatal warnings.
     pub async fn run(&mut self, task: &str) -> Result<String> {
-        // 1. Add user task to memory
-        self.memory.add_message(Message::user(task));
+        self.run_with_result(task)
+            .await
+            .map(|result| result.final_response)
+    }
 
-        // 2. Generate plan
+    /// Execute a task like `run`, returning the full execution result.
+    ///
+    /// The result includes the step results and the warnings collected by the
+    /// executor and tools, so callers can show them to the user.
+    pub async fn run_with_result(&mut self, task: &str) -> Result<ExecutionResult> {
+        // 1. Generate plan (the task itself is passed as the goal, not as context)
         let available_tools = self.executor.list_tools();
         let plan = self.planner.create_plan(task, &available_tools).await?;
 
+        // 2. Add user task to memory
+        self.planner.memory_mut().add_message(Message::user(task));
+
         // 3. Validate plan
         self.guardrails.validate_all(&plan)?;
 
@@ -41,10 +62,20 @@ impl Agent {
         let result = self.executor.execute_plan(plan).await?;
 
         // 5. Store result
-        self.memory
+        self.planner
+            .memory_mut()
             .add_message(Message::assistant(&result.final_response));
 
-        Ok(result.final_response)
+        Ok(result)
+    }
+
+    /// Check whether the agent is ready to serve requests.
+    ///
+    /// Validates the configuration (if one was provided to the builder),
+    /// runs the provider health check, and confirms each configured tool
+    /// name resolved to a registered tool.
+    pub async fn readiness(&self) -> ReadinessReport {
+        check_readiness(self.config.as_ref(), &self.planner, &self.executor).await
     }
 }
 
@@ -55,6 +86,10 @@ pub struct AgentBuilder {
     memory: Option<Box<dyn MemoryStore>>,
     tools: Option<ToolRegistry>,
     guardrails: Option<GuardrailRegistry>,
+    config: Option<AgentConfig>,
+    context_strategy: Option<ContextStrategy>,
+    parallel_tool_calls: bool,
+    max_concurrency: Option<usize>,
 }
 
 impl AgentBuilder {
@@ -64,7 +99,8 @@ impl AgentBuilder {
         self
     }
 
-    /// Sets the memory store (Optional, defaults to InMemoryStore).
+    /// Sets the memory store the conversation is recorded in and planning context is
+    /// selected from (Optional, defaults to InMemoryStore).
     pub fn memory(mut self, memory: Box<dyn MemoryStore>) -> Self {
         self.memory = Some(memory);
         self
@@ -82,6 +118,31 @@ impl AgentBuilder {
         self
     }
 
+    /// Sets how the planner and executor pull context from memory (Optional, defaults to a token
+    /// budget from the configuration's `memory.token_budget`, if one was provided).
+    pub fn context_strategy(mut self, strategy: ContextStrategy) -> Self {
+        self.context_strategy = Some(strategy);
+        self
+    }
+
+    /// Runs consecutive tool calls in a plan concurrently (Optional, defaults to false).
+    pub fn parallel_tool_calls(mut self, enabled: bool) -> Self {
+        self.parallel_tool_calls = enabled;
+        self
+    }
+
+    /// Sets how many tool calls may run at once in parallel mode (Optional, defaults to 4).
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = Some(max_concurrency);
+        self
+    }
+
+    /// Sets the configuration the agent was built from (Optional, used for readiness checks).
+    pub fn config(mut self, config: AgentConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
     /// Builds the Agent.
     ///
     /// # Errors
@@ -147,8 +208,8 @@ impl AgentBuilder {
         // Since `Planner` takes ownership of `llm` and `memory`, and `Executor` takes ownership of `tools` and `memory`.
         // I need to be careful.
 
-        // For now, I will create new InMemoryStores for Planner and Executor if they need them,
-        // but the Agent's main memory is what matters for the outer loop.
+        // The Planner gets the Agent's memory, so its context strategy selects from the
+        // conversation the Agent records. The Executor keeps its own store for step results.
 
         let tools = self.tools.unwrap_or_default();
         let guardrails = self.guardrails.unwrap_or_default();
@@ -158,14 +219,28 @@ impl AgentBuilder {
         // We can't clone `Box<dyn LLMProvider>` easily.
         // But `Planner` needs it.
 
-        let planner = Planner::new(llm, Box::new(memory::InMemoryStore::new()));
-        let executor = Executor::new(tools, Box::new(memory::InMemoryStore::new()));
+        let context_strategy = self
+            .context_strategy
+            .or_else(|| {
+                self.config
+                    .as_ref()
+                    .map(|c| ContextStrategy::TokenBudget(c.memory.token_budget))
+            })
+            .unwrap_or_default();
+
+        let planner = Planner::new(llm, memory).with_context_strategy(context_strategy);
+        let mut executor = Executor::new(tools, Box::new(memory::InMemoryStore::new()))
+            .with_context_strategy(context_strategy)
+            .with_parallel_tool_calls(self.parallel_tool_calls);
+        if let Some(max_concurrency) = self.max_concurrency {
+            executor = executor.with_max_concurrency(max_concurrency);
+        }
 
         Ok(Agent {
-            memory,
             planner,
             executor,
             guardrails,
+            config: self.config,
         })
     }
 }
@@ -197,4 +272,300 @@ mod tests {
         let agent = Agent::builder().build();
         assert!(agent.is_err());
     }
+
+    #[test]
+    fn test_agent_builder_context_strategy_from_config() {
+        let mut config = test_config(Vec::new());
+        config.memory.token_budget = 1234;
+
+        let agent = Agent::builder()
+            .llm(Box::new(MockLLM))
+            .config(config)
+            .build()
+            .unwrap();
+        assert_eq!(
+            agent.planner.context_strategy(),
+            ContextStrategy::TokenBudget(1234)
+        );
+        assert_eq!(
+            agent.executor.context_strategy(),
+            ContextStrategy::TokenBudget(1234)
+        );
+
+        let agent = Agent::builder()
+            .llm(Box::new(MockLLM))
+            .config(test_config(Vec::new()))
+            .context_strategy(ContextStrategy::RecentCount(5))
+            .build()
+            .unwrap();
+        assert_eq!(
+            agent.planner.context_strategy(),
+            ContextStrategy::RecentCount(5)
+        );
+    }
+
+    #[test]
+    fn test_agent_builder_parallel_tool_calls() {
+        let agent = Agent::builder().llm(Box::new(MockLLM)).build().unwrap();
+        assert!(!agent.executor.parallel_tool_calls());
+
+        let agent = Agent::builder()
+            .llm(Box::new(MockLLM))
+            .parallel_tool_calls(true)
+            .max_concurrency(2)
+            .build()
+            .unwrap();
+        assert!(agent.executor.parallel_tool_calls());
+        assert_eq!(agent.executor.max_concurrency(), 2);
+    }
+
+    struct FailingLLM;
+    #[async_trait]
+    impl llm::LLMProvider for FailingLLM {
+        async fn send_message(&self, _messages: &[Message]) -> Result<String> {
+            Err(agent_core::AgentError::LLMProvider(
+                "connection refused".to_string(),
+            ))
+        }
+    }
+
+    fn test_config(tools: Vec<String>) -> AgentConfig {
+        AgentConfig {
+            llm: config::LLMConfig {
+                provider: "openai".to_string(),
+                model: "gpt-4".to_string(),
+                api_key: "test-key".to_string(),
+                base_url: None,
+                temperature: 0.7,
+                max_tokens: 2000,
+                allowed_models: Vec::new(),
+            },
+            memory: config::MemoryConfig {
+                max_messages: 50,
+                token_budget: 4000,
+            },
+            tools,
+            guardrails: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_readiness_all_green() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(tools::Calculator::new()));
+
+        let agent = Agent::builder()
+            .llm(Box::new(MockLLM))
+            .tools(registry)
+            .config(test_config(vec!["calculator".to_string()]))
+            .build()
+            .unwrap();
+
+        let report = agent.readiness().await;
+        assert!(report.is_ready(), "report: {}", report);
+        let names: Vec<&str> = report.checks.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["config", "provider", "tool:calculator"]);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_failing_provider() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(tools::Calculator::new()));
+
+        let agent = Agent::builder()
+            .llm(Box::new(FailingLLM))
+            .tools(registry)
+            .config(test_config(vec!["calculator".to_string()]))
+            .build()
+            .unwrap();
+
+        let report = agent.readiness().await;
+        assert!(!report.is_ready());
+
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].name, "provider");
+        assert!(failures[0].message.contains("connection refused"));
+        assert!(report.to_string().contains("[FAIL] provider"));
+    }
+
+    #[tokio::test]
+    async fn test_readiness_unresolved_tool() {
+        let agent = Agent::builder()
+            .llm(Box::new(MockLLM))
+            .config(test_config(vec!["web_search".to_string()]))
+            .build()
+            .unwrap();
+
+        let report = agent.readiness().await;
+        assert!(!report.is_ready());
+        assert_eq!(report.failures()[0].name, "tool:web_search");
+    }
+
+    /// LLM that counts how often it is called
+    struct CountingLLM {
+        calls: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+    #[async_trait]
+    impl llm::LLMProvider for CountingLLM {
+        async fn send_message(&self, _messages: &[Message]) -> Result<String> {
+            self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok("{}".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_readiness_invalid_config_skips_provider() {
+        let calls = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut config = test_config(Vec::new());
+        config.llm.api_key = String::new();
+
+        let agent = Agent::builder()
+            .llm(Box::new(CountingLLM {
+                calls: calls.clone(),
+            }))
+            .config(config)
+            .build()
+            .unwrap();
+
+        let report = agent.readiness().await;
+        assert!(!report.is_ready());
+        assert_eq!(calls.load(std::sync::atomic::Ordering::SeqCst), 0);
+
+        // The config error is the only failure; the provider check is skipped
+        let failures = report.failures();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].name, "config");
+        assert_eq!(report.checks[1].name, "provider");
+        assert_eq!(report.checks[1].status, CheckStatus::Skipped);
+        assert_eq!(report.checks[1].message, "skipped: invalid config");
+        assert!(report.to_string().contains("[SKIP] provider"));
+    }
+
+    #[test]
+    fn test_readiness_report_from_build_error() {
+        let error = agent_core::AgentError::Config("Unsupported provider: foo".to_string());
+
+        let report = ReadinessReport::from_build_error(&test_config(Vec::new()), &error);
+        let statuses: Vec<(&str, CheckStatus)> = report
+            .checks
+            .iter()
+            .map(|c| (c.name.as_str(), c.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("config", CheckStatus::Pass),
+                ("init", CheckStatus::Fail),
+                ("provider", CheckStatus::Skipped),
+            ]
+        );
+        assert!(report.checks[1]
+            .message
+            .contains("Unsupported provider: foo"));
+
+        // An invalid config is reported once; the checks that depend on it are skipped
+        let mut config = test_config(Vec::new());
+        config.llm.api_key = String::new();
+        let report = ReadinessReport::from_build_error(&config, &error);
+        assert_eq!(report.failures().len(), 1);
+        assert_eq!(report.failures()[0].name, "config");
+        assert_eq!(report.checks[1].status, CheckStatus::Skipped);
+        assert_eq!(report.checks[2].status, CheckStatus::Skipped);
+    }
+
+    #[tokio::test]
+    async fn test_readiness_without_config_is_skipped() {
+        let agent = Agent::builder().llm(Box::new(MockLLM)).build().unwrap();
+
+        let report = agent.readiness().await;
+        assert!(report.is_ready(), "report: {}", report);
+        assert_eq!(report.checks[0].status, CheckStatus::Skipped);
+        assert!(report.to_string().contains("[SKIP] config"));
+    }
+
+    /// LLM that always answers with the same plan
+    struct PlanLLM(String);
+    #[async_trait]
+    impl llm::LLMProvider for PlanLLM {
+        async fn send_message(&self, _messages: &[Message]) -> Result<String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_with_result_exposes_warnings() {
+        let plan = serde_json::json!({
+            "reasoning": "Search twice",
+            "steps": [
+                {"type": "tool_call", "tool_name": "web_search", "parameters": {"query": "rust"}},
+                {"type": "tool_call", "tool_name": "web_search", "parameters": {"query": "rust"}},
+                {"type": "response", "text": "Done"}
+            ]
+        });
+
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(tools::WebSearchStub::new()));
+
+        let mut agent = Agent::builder()
+            .llm(Box::new(PlanLLM(plan.to_string())))
+            .tools(registry)
+            .build()
+            .unwrap();
+
+        let result = agent.run_with_result("Search for rust").await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.final_response, "Done");
+        assert_eq!(
+            result.warnings,
+            vec!["web_search: Served cached results for 'rust'"]
+        );
+    }
+
+    /// LLM that records every message list it receives and answers with a response plan
+    struct RecordingLLM {
+        received: std::sync::Arc<std::sync::Mutex<Vec<Vec<Message>>>>,
+    }
+    #[async_trait]
+    impl llm::LLMProvider for RecordingLLM {
+        async fn send_message(&self, messages: &[Message]) -> Result<String> {
+            self.received.lock().unwrap().push(messages.to_vec());
+            Ok(
+                r#"{"reasoning": "Answer", "steps": [{"type": "response", "text": "Answer"}]}"#
+                    .to_string(),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_plans_with_context_from_earlier_turns() {
+        let received = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let mut agent = Agent::builder()
+            .llm(Box::new(RecordingLLM {
+                received: received.clone(),
+            }))
+            .context_strategy(ContextStrategy::RecentCount(1))
+            .build()
+            .unwrap();
+
+        agent.run("First task").await.unwrap();
+        agent.run("Second task").await.unwrap();
+
+        let received = received.lock().unwrap();
+        // First turn: system prompt and goal only
+        assert_eq!(received[0].len(), 2);
+        // Second turn: the strategy picked only the latest recorded message
+        let contents: Vec<&str> = received[1].iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(contents.len(), 3);
+        assert_eq!(contents[1], "Answer");
+        assert_eq!(contents[2], "Second task");
+    }
+
+    #[test]
+    fn test_default_context_strategy_matches_config_default() {
+        assert_eq!(
+            ContextStrategy::default(),
+            ContextStrategy::TokenBudget(config::DEFAULT_TOKEN_BUDGET)
+        );
+    }
 }
@@ -0,0 +1,195 @@
+use agent_core::AgentError;
+use config::AgentConfig;
+use executor::Executor;
+use planner::Planner;
+use std::fmt;
+
+/// Message for checks skipped because the configuration is invalid
+const SKIPPED_INVALID_CONFIG: &str = "skipped: invalid config";
+
+/// Status of a single readiness check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The check ran and passed
+    Pass,
+    /// The check ran and failed
+    Fail,
+    /// The check did not run because an earlier check made it meaningless
+    Skipped,
+}
+
+/// Outcome of a single readiness check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadinessCheck {
+    /// Name of the check (e.g. "config", "provider", "tool:calculator")
+    pub name: String,
+    /// Whether the check passed, failed, or was skipped
+    pub status: CheckStatus,
+    /// Human-readable detail about the outcome
+    pub message: String,
+}
+
+impl ReadinessCheck {
+    /// Create a passing check
+    pub fn pass(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Pass, message)
+    }
+
+    /// Create a failing check
+    pub fn fail(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Fail, message)
+    }
+
+    /// Create a skipped check
+    pub fn skipped(name: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::new(name, CheckStatus::Skipped, message)
+    }
+
+    fn new(name: impl Into<String>, status: CheckStatus, message: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// Returns true if the check ran and failed.
+    pub fn failed(&self) -> bool {
+        self.status == CheckStatus::Fail
+    }
+}
+
+/// Combined result of all readiness checks for an agent.
+#[derive(Debug, Clone, Default)]
+pub struct ReadinessReport {
+    /// Individual check results, in the order they were run
+    pub checks: Vec<ReadinessCheck>,
+}
+
+impl ReadinessReport {
+    /// Returns true if no check failed.
+    pub fn is_ready(&self) -> bool {
+        !self.checks.iter().any(ReadinessCheck::failed)
+    }
+
+    /// Returns the checks that failed.
+    pub fn failures(&self) -> Vec<&ReadinessCheck> {
+        self.checks.iter().filter(|check| check.failed()).collect()
+    }
+
+    /// Build a report for an agent that could not be constructed.
+    ///
+    /// The configuration is still checked first. The construction error is
+    /// reported as a failed "init" check, unless the configuration was
+    /// already invalid. The provider health check cannot run and is skipped.
+    ///
+    /// # Arguments
+    /// * `config` - The configuration the agent was built from
+    /// * `error` - The error returned while constructing the agent
+    pub fn from_build_error(config: &AgentConfig, error: &AgentError) -> Self {
+        let config_check = check_config(Some(config));
+        let (init_check, provider_check) = if config_check.failed() {
+            (
+                ReadinessCheck::skipped("init", SKIPPED_INVALID_CONFIG),
+                ReadinessCheck::skipped("provider", SKIPPED_INVALID_CONFIG),
+            )
+        } else {
+            (
+                ReadinessCheck::fail("init", error.to_string()),
+                ReadinessCheck::skipped("provider", "skipped: agent could not be initialized"),
+            )
+        };
+
+        Self {
+            checks: vec![config_check, init_check, provider_check],
+        }
+    }
+}
+
+impl fmt::Display for ReadinessReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            let status = match check.status {
+                CheckStatus::Pass => "PASS",
+                CheckStatus::Fail => "FAIL",
+                CheckStatus::Skipped => "SKIP",
+            };
+            writeln!(f, "[{}] {}: {}", status, check.name, check.message)?;
+        }
+        write!(f, "Ready: {}", if self.is_ready() { "yes" } else { "no" })
+    }
+}
+
+/// Run the readiness checks for an agent's components.
+///
+/// Checks, in order:
+/// 1. Configuration validity (skipped if no configuration is available)
+/// 2. The LLM provider health check (skipped if the configuration is invalid)
+/// 3. That each configured tool name resolved to a registered tool
+///
+/// # Arguments
+/// * `config` - The configuration the agent was built from, if any
+/// * `planner` - The planner holding the LLM provider
+/// * `executor` - The executor holding the tool registry
+pub async fn check_readiness(
+    config: Option<&AgentConfig>,
+    planner: &Planner,
+    executor: &Executor,
+) -> ReadinessReport {
+    let mut checks = Vec::new();
+
+    // 1. Configuration
+    let config_check = check_config(config);
+    let config_valid = !config_check.failed();
+    checks.push(config_check);
+
+    // 2. Provider (don't spend a request on a provider built from an invalid config)
+    if !config_valid {
+        checks.push(ReadinessCheck::skipped("provider", SKIPPED_INVALID_CONFIG));
+    } else {
+        match planner.health_check().await {
+            Ok(()) => checks.push(ReadinessCheck::pass("provider", "Provider is reachable")),
+            Err(e) => checks.push(ReadinessCheck::fail("provider", e.to_string())),
+        }
+    }
+
+    // 3. Tools
+    let registered = executor.tool_names();
+    let configured = config.map(|c| c.tools.as_slice()).unwrap_or_default();
+    if configured.is_empty() {
+        let message = if registered.is_empty() {
+            "No tools registered".to_string()
+        } else {
+            format!("Registered tools: {}", registered.join(", "))
+        };
+        checks.push(ReadinessCheck::pass("tools", message));
+    }
+    for name in configured {
+        let check_name = format!("tool:{}", name);
+        if registered.contains(&name.as_str()) {
+            checks.push(ReadinessCheck::pass(check_name, "Registered"));
+        } else {
+            checks.push(ReadinessCheck::fail(
+                check_name,
+                format!(
+                    "Configured tool '{}' is not registered. Registered tools: {}",
+                    name,
+                    registered.join(", ")
+                ),
+            ));
+        }
+    }
+
+    ReadinessReport { checks }
+}
+
+/// Validate the configuration, if one is available.
+fn check_config(config: Option<&AgentConfig>) -> ReadinessCheck {
+    match config {
+        Some(config) => match config::validate(config) {
+            Ok(()) => ReadinessCheck::pass("config", "Configuration is valid"),
+            Err(e) => ReadinessCheck::fail("config", e.to_string()),
+        },
+        None => ReadinessCheck::skipped("config", "No configuration provided"),
+    }
+}
@@ -4,12 +4,13 @@
 //! components (LLM, memory, planner, executor, tools, guardrails) to process
 //! user queries.
 
+use ::agent::{check_readiness, ReadinessReport};
 use agent_core::Result;
 use config::AgentConfig;
-use executor::Executor;
+use executor::{ExecutionResult, Executor};
 use guardrails::{FilePathGuardrail, GuardrailRegistry, RateLimitGuardrail};
 use llm::create_provider;
-use memory::{InMemoryStore, MemoryStore};
+use memory::{ContextStrategy, InMemoryStore};
 use planner::Planner;
 use tools::{Calculator, FileReader, ToolRegistry, WebSearchStub};
 
@@ -21,10 +22,10 @@ use tools::{Calculator, FileReader, ToolRegistry, WebSearchStub};
 /// - Executor: Runs plans and invokes tools
 /// - Guardrails: Validates plans before execution
 pub struct Agent {
-    memory: Box<dyn MemoryStore>,
     planner: Planner,
     executor: Executor,
     guardrails: GuardrailRegistry,
+    config: AgentConfig,
 }
 
 impl Agent {
@@ -41,9 +42,6 @@ impl Agent {
     /// - LLM provider initialization fails
     /// - Configuration is invalid
     pub fn new(config: AgentConfig) -> Result<Self> {
-        // Create memory store
-        let memory = Box::new(InMemoryStore::new());
-
         // Create tool registry and register default tools
         let mut tools = ToolRegistry::new();
 
@@ -58,14 +56,16 @@ impl Agent {
             tools.register(Box::new(WebSearchStub::new()));
         }
 
-        // Create planner with LLM and memory
-        let planner_memory = Box::new(InMemoryStore::new());
+        // Create planner with LLM and the memory store the conversation is recorded in
+        let memory = Box::new(InMemoryStore::new());
         let planner_llm = create_provider(&config.llm)?;
-        let planner = Planner::new(planner_llm, planner_memory);
+        let context_strategy = ContextStrategy::TokenBudget(config.memory.token_budget);
+        let planner = Planner::new(planner_llm, memory).with_context_strategy(context_strategy);
 
         // Create executor with tools and memory
         let executor_memory = Box::new(InMemoryStore::new());
-        let executor = Executor::new(tools, executor_memory);
+        let executor =
+            Executor::new(tools, executor_memory).with_context_strategy(context_strategy);
 
         // Create guardrails registry and register default guardrails
         let mut guardrails = GuardrailRegistry::new();
@@ -85,49 +85,64 @@ impl Agent {
         }
 
         Ok(Self {
-            memory,
             planner,
             executor,
             guardrails,
+            config,
         })
     }
 
     /// Process a user query and return a response
     ///
     /// This method orchestrates the complete agent workflow:
-    /// 1. Adds the user query to memory
-    /// 2. Uses the planner to create a plan from the query
+    /// 1. Uses the planner to create a plan from the query and earlier turns
+    /// 2. Adds the user query to memory
     /// 3. Validates the plan with guardrails
     /// 4. Executes the plan with the executor
-    /// 5. Returns the final response
+    /// 5. Adds the response to memory and returns the execution result
     ///
     /// # Arguments
     /// * `query` - The user's query or request
     ///
     /// # Returns
-    /// * `Result<String>` - The agent's response or an error
+    /// * `Result<ExecutionResult>` - The final response and any non-fatal warnings, or an error
     ///
     /// # Errors
     /// Returns an error if:
     /// - Plan generation fails
     /// - Guardrail validation fails
     /// - Plan execution fails
-    pub async fn process(&mut self, query: &str) -> Result<String> {
-        // Add user query to memory
-        let user_message = agent_core::Message::user(query);
-        self.memory.add_message(user_message);
-
+    pub async fn process(&mut self, query: &str) -> Result<ExecutionResult> {
         // Use planner to create plan from query
         let available_tools = self.executor.list_tools();
         let plan = self.planner.create_plan(query, &available_tools).await?;
 
+        // Add user query to the planner's memory so later plans see it
+        let user_message = agent_core::Message::user(query);
+        self.planner.memory_mut().add_message(user_message);
+
         // Validate plan with guardrails
         self.guardrails.validate_all(&plan)?;
 
         // Execute plan with executor
         let result = self.executor.execute_plan(plan).await?;
 
-        // Return final response
-        Ok(result.final_response)
+        // Add response to memory
+        self.planner
+            .memory_mut()
+            .add_message(agent_core::Message::assistant(&result.final_response));
+
+        Ok(result)
+    }
+
+    /// Check whether the agent is ready to serve requests
+    ///
+    /// Validates the configuration, runs the provider health check, and
+    /// confirms each configured tool name resolved to a registered tool.
+    ///
+    /// # Returns
+    /// * `ReadinessReport` - Per-check pass/fail results with messages
+    pub async fn readiness(&self) -> ReadinessReport {
+        check_readiness(Some(&self.config), &self.planner, &self.executor).await
     }
 }
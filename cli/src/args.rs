@@ -20,4 +20,10 @@ pub struct CliArgs {
     /// Enable verbose logging for debugging
     #[arg(short, long)]
     pub verbose: bool,
+
+    /// Print a readiness report (config, provider, tools) and exit
+    /// Exits with a non-zero status if any check fails. The provider check
+    /// makes one real API request that lists models; it does not generate text
+    #[arg(long)]
+    pub ready: bool,
 }
@@ -20,12 +20,19 @@
 //! ```bash
 //! ai-agent --config config.yaml --verbose
 //! ```
+//!
+//! Readiness check (the provider check lists models, which is one real API
+//! request but generates no text):
+//! ```bash
+//! ai-agent --config config.yaml --ready
+//! ```
 
 mod agent;
 mod args;
 mod repl;
 mod single;
 
+use ::agent::ReadinessReport;
 use agent::Agent;
 use args::CliArgs;
 use clap::Parser;
@@ -75,6 +82,20 @@ async fn main() -> anyhow::Result<()> {
         );
     }
 
+    // Readiness mode reports config and initialization problems instead of bailing out early
+    if args.ready {
+        let report = match Agent::new(config.clone()) {
+            Ok(agent) => agent.readiness().await,
+            Err(e) => ReadinessReport::from_build_error(&config, &e),
+        };
+
+        println!("{}", report);
+        if !report.is_ready() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
     // Validate configuration
     config::validate(&config).map_err(|e| {
         eprintln!("{} {}", "Configuration Error:".bright_red().bold(), e);
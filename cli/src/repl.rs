@@ -4,6 +4,7 @@
 //! history support, and colored output.
 
 use crate::agent::Agent;
+use crate::single::print_warnings;
 use agent_core::Result;
 use colored::Colorize;
 use rustyline::DefaultEditor;
@@ -89,9 +90,10 @@ pub async fn run(mut agent: Agent) -> Result<()> {
 
                 // Process the query with the agent
                 match agent.process(trimmed).await {
-                    Ok(response) => {
+                    Ok(result) => {
                         // Print response with colored output (green for success)
-                        println!("\n{}\n", response.bright_white());
+                        println!("\n{}\n", result.final_response.bright_white());
+                        print_warnings(&result.warnings);
                     }
                     Err(e) => {
                         // Print error with colored output (red for errors)
@@ -9,7 +9,8 @@ use colored::Colorize;
 
 /// Run the agent in single-turn mode with a single query
 ///
-/// This function processes one query and prints the response to stdout.
+/// This function processes one query and prints the response to stdout,
+/// followed by any non-fatal warnings on stderr.
 /// It's useful for scripting and one-off queries.
 ///
 /// # Arguments
@@ -25,10 +26,21 @@ use colored::Colorize;
 /// - Output cannot be written to stdout
 pub async fn run(agent: &mut Agent, query: &str) -> Result<()> {
     // Process the query
-    let response = agent.process(query).await?;
+    let result = agent.process(query).await?;
 
     // Print response to stdout with success color
-    println!("{}", response.bright_white());
+    println!("{}", result.final_response.bright_white());
+    print_warnings(&result.warnings);
 
     Ok(())
 }
+
+/// Print non-fatal warnings from an execution to stderr
+///
+/// # Arguments
+/// * `warnings` - The warnings collected while executing the plan
+pub fn print_warnings(warnings: &[String]) {
+    for warning in warnings {
+        eprintln!("{} {}", "Warning:".bright_yellow().bold(), warning);
+    }
+}
@@ -63,23 +63,43 @@ impl ApiClient {
         T: Serialize,
         R: for<'de> Deserialize<'de>,
     {
-        let response = self
-            .client
-            .post(url)
-            .headers(headers)
-            .json(body)
-            .timeout(self.timeout)
-            .send()
+        self.send_json(self.client.post(url).headers(headers).json(body))
             .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    AgentError::LLMProvider(format!("Request timeout: {}", e))
-                } else if e.is_connect() {
-                    AgentError::LLMProvider(format!("Connection error: {}", e))
-                } else {
-                    AgentError::LLMProvider(format!("Request failed: {}", e))
-                }
-            })?;
+    }
+
+    /// Send a GET request with custom headers and deserialize the JSON response
+    ///
+    /// # Arguments
+    /// * `url` - The URL to send the request to
+    /// * `headers` - Custom headers to include in the request
+    ///
+    /// # Returns
+    /// The deserialized response or an error
+    pub async fn get_json_with_headers<R>(
+        &self,
+        url: &str,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        self.send_json(self.client.get(url).headers(headers)).await
+    }
+
+    /// Send a prepared request, check the HTTP status, and deserialize the JSON response
+    async fn send_json<R>(&self, request: reqwest::RequestBuilder) -> Result<R>
+    where
+        R: for<'de> Deserialize<'de>,
+    {
+        let response = request.timeout(self.timeout).send().await.map_err(|e| {
+            if e.is_timeout() {
+                AgentError::LLMProvider(format!("Request timeout: {}", e))
+            } else if e.is_connect() {
+                AgentError::LLMProvider(format!("Connection error: {}", e))
+            } else {
+                AgentError::LLMProvider(format!("Request failed: {}", e))
+            }
+        })?;
 
         // Check for HTTP errors
         let status = response.status();
@@ -178,6 +198,30 @@ mod tests {
         assert_eq!(response.reply, "Hello back!");
     }
 
+    #[tokio::test]
+    async fn test_successful_get_request() {
+        // Start a mock server
+        let mock_server = MockServer::start().await;
+
+        let response_body = TestResponse {
+            reply: "Listed".to_string(),
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/list"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&response_body))
+            .mount(&mock_server)
+            .await;
+
+        let client = ApiClient::new();
+        let url = format!("{}/list", mock_server.uri());
+        let result: Result<TestResponse> = client
+            .get_json_with_headers(&url, reqwest::header::HeaderMap::new())
+            .await;
+
+        assert_eq!(result.unwrap().reply, "Listed");
+    }
+
     #[tokio::test]
     async fn test_http_error_handling() {
         // Start a mock server
@@ -52,6 +52,9 @@ pub struct LLMConfig {
     /// Maximum tokens in response
     #[serde(default = "default_max_tokens")]
     pub max_tokens: usize,
+    /// Models this deployment may use; empty means no restriction
+    #[serde(default)]
+    pub allowed_models: Vec<String>,
 }
 
 /// Configuration for the memory system
@@ -78,8 +81,11 @@ fn default_max_messages() -> usize {
     50
 }
 
+/// Default token budget for the context window
+pub const DEFAULT_TOKEN_BUDGET: usize = 4000;
+
 fn default_token_budget() -> usize {
-    4000
+    DEFAULT_TOKEN_BUDGET
 }
 
 /// Load agent configuration from a YAML file
@@ -162,9 +168,12 @@ pub fn load_defaults() -> Result<AgentConfig> {
 /// - LLM provider, model, API key, temperature, and max_tokens
 /// - Memory settings are taken from file config if present
 /// - Tools and guardrails are taken from file config
+/// - The model allow-list is taken from file config
 pub fn merge(mut file_config: AgentConfig, env_config: AgentConfig) -> AgentConfig {
-    // Override LLM config with env values
+    // Override LLM config with env values, keeping the file's model allow-list
+    let allowed_models = std::mem::take(&mut file_config.llm.allowed_models);
     file_config.llm = env_config.llm;
+    file_config.llm.allowed_models = allowed_models;
 
     // Keep file-based memory, tools, and guardrails settings
     // (env config doesn't provide these)
@@ -185,6 +194,7 @@ pub fn merge(mut file_config: AgentConfig, env_config: AgentConfig) -> AgentConf
 /// - API key is empty
 /// - Provider is empty
 /// - Model is empty
+/// - Model is not in a non-empty `allowed_models` list
 pub fn validate(config: &AgentConfig) -> Result<()> {
     if config.llm.api_key.is_empty() {
         return Err(AgentError::Config(
@@ -204,6 +214,16 @@ pub fn validate(config: &AgentConfig) -> Result<()> {
         ));
     }
 
+    if !config.llm.allowed_models.is_empty()
+        && !config.llm.allowed_models.contains(&config.llm.model)
+    {
+        return Err(AgentError::Config(format!(
+            "Model '{}' is not allowed. Allowed models: {}",
+            config.llm.model,
+            config.llm.allowed_models.join(", ")
+        )));
+    }
+
     if config.llm.temperature < 0.0 || config.llm.temperature > 2.0 {
         return Err(AgentError::Config(format!(
             "Temperature must be between 0.0 and 2.0, got {}",
@@ -292,6 +312,7 @@ pub fn from_env() -> Result<AgentConfig> {
             }),
             temperature,
             max_tokens,
+            allowed_models: Vec::new(),
         },
         memory: MemoryConfig {
             max_messages: default_max_messages(),
@@ -651,6 +672,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.5,
                 max_tokens: 1000,
+                allowed_models: vec!["claude-3".to_string()],
             },
             memory: MemoryConfig {
                 max_messages: 30,
@@ -668,6 +690,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.9,
                 max_tokens: 2000,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -686,6 +709,9 @@ guardrails:
         assert_eq!(merged.llm.temperature, 0.9);
         assert_eq!(merged.llm.max_tokens, 2000);
 
+        // Model allow-list should come from file
+        assert_eq!(merged.llm.allowed_models, vec!["claude-3"]);
+
         // Memory, tools, and guardrails should come from file
         assert_eq!(merged.memory.max_messages, 30);
         assert_eq!(merged.memory.token_budget, 3000);
@@ -703,6 +729,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.7,
                 max_tokens: 2000,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -725,6 +752,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.7,
                 max_tokens: 2000,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -749,6 +777,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.7,
                 max_tokens: 2000,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -773,6 +802,7 @@ guardrails:
                 base_url: None,
                 temperature: 3.0,
                 max_tokens: 2000,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -797,6 +827,7 @@ guardrails:
                 base_url: None,
                 temperature: 0.7,
                 max_tokens: 0,
+                allowed_models: Vec::new(),
             },
             memory: MemoryConfig {
                 max_messages: 50,
@@ -810,4 +841,72 @@ guardrails:
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Max tokens"));
     }
+
+    fn config_with_allowed_models(model: &str, allowed_models: Vec<String>) -> AgentConfig {
+        AgentConfig {
+            llm: LLMConfig {
+                provider: "openai".to_string(),
+                model: model.to_string(),
+                api_key: "test-key".to_string(),
+                base_url: None,
+                temperature: 0.7,
+                max_tokens: 2000,
+                allowed_models,
+            },
+            memory: MemoryConfig {
+                max_messages: 50,
+                token_budget: 4000,
+            },
+            tools: Vec::new(),
+            guardrails: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_validate_allowed_model() {
+        let config = config_with_allowed_models(
+            "gpt-3.5-turbo",
+            vec!["gpt-3.5-turbo".to_string(), "gpt-4o-mini".to_string()],
+        );
+
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn test_validate_disallowed_model() {
+        let config = config_with_allowed_models(
+            "gpt-4",
+            vec!["gpt-3.5-turbo".to_string(), "gpt-4o-mini".to_string()],
+        );
+
+        let result = validate(&config);
+        assert!(result.is_err());
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("'gpt-4' is not allowed"));
+        assert!(message.contains("gpt-3.5-turbo, gpt-4o-mini"));
+    }
+
+    #[test]
+    fn test_validate_empty_allowed_models_is_unrestricted() {
+        let config = config_with_allowed_models("any-model", Vec::new());
+
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_models_from_yaml() {
+        let config_str = r#"
+            llm:
+              provider: openai
+              model: gpt-4
+              api_key: test-key
+              allowed_models:
+                - gpt-3.5-turbo
+            memory: {}
+        "#;
+
+        let config: AgentConfig = serde_yaml::from_str(config_str).unwrap();
+        assert_eq!(config.llm.allowed_models, vec!["gpt-3.5-turbo"]);
+        assert!(validate(&config).is_err());
+    }
 }
@@ -11,6 +11,15 @@ pub enum AgentError {
     #[error("LLM provider error: {0}")]
     LLMProvider(String),
 
+    /// LLM response stopped without a usable answer
+    #[error("LLM provider error: {message} (finish_reason: {reason})")]
+    LLMFinish {
+        /// Why the provider stopped, e.g. "content_filter", "length", or "refusal"
+        reason: String,
+        /// Human-readable detail
+        message: String,
+    },
+
     /// Tool execution failed
     #[error("Tool execution failed: {tool_name} - {reason}")]
     ToolExecution {
@@ -79,6 +88,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_llm_finish_error() {
+        let err = AgentError::LLMFinish {
+            reason: "length".to_string(),
+            message: "response was truncated".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "LLM provider error: response was truncated (finish_reason: length)"
+        );
+    }
+
     #[test]
     fn test_io_error_conversion() {
         let io_err = io::Error::new(io::ErrorKind::NotFound, "file not found");
@@ -38,6 +38,7 @@ async fn main() -> Result<()> {
         base_url: Some("http://localhost:11434".to_string()),
         temperature: 0.7,
         max_tokens: 2000,
+        allowed_models: Vec::new(),
     };
 
     println!("{} {}", "Using model:".bright_black(), config.model.bright_white());
@@ -1,19 +1,34 @@
 use agent_core::{Message, Result};
-use memory::MemoryStore;
+use futures::future::join_all;
+use memory::{ContextStrategy, MemoryStore};
 use planner::{Plan, Step};
+use tokio::sync::Semaphore;
 use tools::ToolRegistry;
 
 use crate::types::{ExecutionResult, StepResult};
 
+/// Default upper bound on tool calls running at the same time
+const DEFAULT_MAX_CONCURRENCY: usize = 4;
+
 /// The Executor is responsible for running plans generated by the planner.
 ///
 /// It takes a plan with a sequence of steps and executes them in order,
 /// managing tool invocations and storing results in memory for context.
+/// Consecutive tool calls can optionally run concurrently, bounded by a
+/// configurable concurrency limit.
 pub struct Executor {
     /// Registry of available tools
     tools: ToolRegistry,
     /// Memory store for conversation context
     memory: Box<dyn MemoryStore>,
+    /// Strategy used to pull conversation context from memory
+    context_strategy: ContextStrategy,
+    /// Whether consecutive tool calls run concurrently
+    parallel_tool_calls: bool,
+    /// Maximum number of tool calls running at the same time
+    max_concurrency: usize,
+    /// Non-fatal warnings collected while executing the current plan
+    warnings: Vec<String>,
 }
 
 impl Executor {
@@ -26,7 +41,82 @@ impl Executor {
     /// # Returns
     /// A new Executor instance
     pub fn new(tools: ToolRegistry, memory: Box<dyn MemoryStore>) -> Self {
-        Self { tools, memory }
+        Self {
+            tools,
+            memory,
+            context_strategy: ContextStrategy::default(),
+            parallel_tool_calls: false,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Enables or disables concurrent execution of consecutive tool calls.
+    ///
+    /// When enabled, each run of adjacent ToolCall steps is executed
+    /// concurrently (up to the concurrency limit). Results are still recorded
+    /// in plan order, and a failure stops execution after the current run.
+    /// Every call in that run has already executed, so all of its outcomes
+    /// (and their warnings) are recorded.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to run consecutive tool calls concurrently
+    ///
+    /// # Returns
+    /// The Executor with the setting applied
+    pub fn with_parallel_tool_calls(mut self, enabled: bool) -> Self {
+        self.parallel_tool_calls = enabled;
+        self
+    }
+
+    /// Sets the maximum number of tool calls that may run at the same time.
+    ///
+    /// This protects downstream services from bursts of concurrent requests
+    /// during parallel execution. Values below 1 are treated as 1.
+    ///
+    /// # Arguments
+    /// * `max_concurrency` - Upper bound on simultaneously running tool calls
+    ///
+    /// # Returns
+    /// The Executor with the limit applied
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Returns whether consecutive tool calls run concurrently.
+    pub fn parallel_tool_calls(&self) -> bool {
+        self.parallel_tool_calls
+    }
+
+    /// Returns the maximum number of tool calls run at once in parallel mode.
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Sets the strategy used to pull conversation context from memory.
+    ///
+    /// # Arguments
+    /// * `strategy` - Which memory getter to use when assembling context
+    ///
+    /// # Returns
+    /// The Executor with the strategy applied
+    pub fn with_context_strategy(mut self, strategy: ContextStrategy) -> Self {
+        self.context_strategy = strategy;
+        self
+    }
+
+    /// Returns the strategy used to pull conversation context from memory.
+    pub fn context_strategy(&self) -> ContextStrategy {
+        self.context_strategy
+    }
+
+    /// Returns the conversation context selected by the configured strategy.
+    ///
+    /// # Returns
+    /// Messages from memory in chronological order
+    pub fn context(&self) -> Vec<Message> {
+        self.context_strategy.select(self.memory.as_ref())
     }
 
     /// Lists all available tools in the registry.
@@ -37,50 +127,88 @@ impl Executor {
         self.tools.list_tools()
     }
 
+    /// Lists the names of all tools in the registry.
+    ///
+    /// # Returns
+    /// A vector of tool names, sorted alphabetically
+    pub fn tool_names(&self) -> Vec<&str> {
+        self.tools.names()
+    }
+
     /// Executes a complete plan by running each step sequentially.
     ///
     /// This method iterates through all steps in the plan, executing each one
     /// and collecting the results. After each step, the result is added to memory
-    /// to provide context for subsequent steps.
+    /// to provide context for subsequent steps. If parallel tool calls are
+    /// enabled, adjacent tool calls are executed together as one batch.
     ///
     /// # Arguments
     /// * `plan` - The plan to execute
     ///
     /// # Returns
-    /// An ExecutionResult containing the success status, final response, and all step results
+    /// An ExecutionResult containing the success status, final response, all step results,
+    /// and any non-fatal warnings reported while running the plan
     pub async fn execute_plan(&mut self, plan: Plan) -> Result<ExecutionResult> {
+        self.warnings.clear();
         let mut step_results = Vec::new();
         let mut final_response = String::new();
         let mut overall_success = true;
 
-        // Execute each step in sequence
-        for step in plan.steps {
-            match self.execute_step(&step).await {
-                Ok(step_result) => {
-                    // Add result to memory for context
-                    let message = Message::assistant(step_result.output.clone());
-                    self.memory.add_message(message);
-
-                    // If this is a Response step, use it as the final response
-                    if step_result.step_type == "response" {
-                        final_response = step_result.output.clone();
+        // Execute each step in sequence, batching adjacent tool calls if enabled
+        let mut steps = plan.steps.into_iter().peekable();
+        while let Some(step) = steps.next() {
+            let outcomes = match step {
+                Step::ToolCall(tool_call) if self.parallel_tool_calls => {
+                    let mut batch = vec![tool_call];
+                    while let Some(Step::ToolCall(next)) =
+                        steps.next_if(|s| matches!(s, Step::ToolCall(_)))
+                    {
+                        batch.push(next);
                     }
-
-                    step_results.push(step_result);
+                    self.execute_tool_calls_concurrently(&batch).await
                 }
-                Err(e) => {
-                    // Step failed - record the failure and stop execution
-                    let step_result =
-                        StepResult::failure("error", format!("Step execution failed: {}", e));
-                    step_results.push(step_result);
-                    overall_success = false;
-                    break;
+                step => vec![self.execute_step(&step).await],
+            };
+
+            for outcome in outcomes {
+                match outcome {
+                    Ok(step_result) => {
+                        // Add result to memory for context
+                        let message = Message::assistant(step_result.output.clone());
+                        self.memory.add_message(message);
+
+                        // If this is a Response step, use it as the final response
+                        if step_result.step_type == "response" {
+                            final_response = step_result.output.clone();
+                        }
+
+                        step_results.push(step_result);
+                    }
+                    Err(e) => {
+                        // Step failed - record the failure; the rest of a
+                        // concurrent batch has already run and is still recorded
+                        let step_result =
+                            StepResult::failure("error", format!("Step execution failed: {}", e));
+                        step_results.push(step_result);
+                        overall_success = false;
+                    }
                 }
             }
+
+            // Stop execution after the step (or batch) that failed
+            if !overall_success {
+                break;
+            }
         }
 
         // If no explicit response step was found, build a response from the results
         if final_response.is_empty() && !step_results.is_empty() {
+            if overall_success {
+                self.warnings.push(
+                    "Plan had no response step; final response was built from step outputs"
+                        .to_string(),
+                );
+            }
             final_response = step_results
                 .iter()
                 .filter(|r| r.success)
@@ -93,6 +221,7 @@ impl Executor {
             success: overall_success,
             final_response,
             step_results,
+            warnings: std::mem::take(&mut self.warnings),
         })
     }
 
@@ -115,6 +244,46 @@ impl Executor {
         }
     }
 
+    /// Executes a batch of tool calls concurrently.
+    ///
+    /// At most `max_concurrency` tool calls run at the same time; the rest wait
+    /// for a permit. Outcomes are returned in the same order as the batch.
+    ///
+    /// # Arguments
+    /// * `tool_calls` - The tool calls to execute
+    ///
+    /// # Returns
+    /// One StepResult or error per tool call
+    async fn execute_tool_calls_concurrently(
+        &mut self,
+        tool_calls: &[planner::ToolCall],
+    ) -> Vec<Result<StepResult>> {
+        let semaphore = Semaphore::new(self.max_concurrency);
+
+        let outcomes = {
+            let this = &*self;
+            let semaphore = &semaphore;
+            join_all(tool_calls.iter().map(|tool_call| async move {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+                this.invoke_tool(tool_call).await
+            }))
+            .await
+        };
+
+        outcomes
+            .into_iter()
+            .map(|outcome| {
+                outcome.map(|(step_result, warnings)| {
+                    self.warnings.extend(warnings);
+                    step_result
+                })
+            })
+            .collect()
+    }
+
     /// Handles the execution of a tool call.
     ///
     /// This method looks up the tool in the registry, executes it with the
@@ -127,6 +296,25 @@ impl Executor {
     /// # Returns
     /// A StepResult containing the tool output or an error
     async fn handle_tool_call(&mut self, tool_call: &planner::ToolCall) -> Result<StepResult> {
+        let (step_result, warnings) = self.invoke_tool(tool_call).await?;
+        self.warnings.extend(warnings);
+        Ok(step_result)
+    }
+
+    /// Looks up and runs a tool, returning its StepResult and any warnings.
+    ///
+    /// Tools report non-fatal conditions through an optional `warnings` array
+    /// of strings in their JSON result; these are collected for the ExecutionResult.
+    ///
+    /// # Arguments
+    /// * `tool_call` - The tool call to execute
+    ///
+    /// # Returns
+    /// The StepResult and the tool's warnings, or an error
+    async fn invoke_tool(
+        &self,
+        tool_call: &planner::ToolCall,
+    ) -> Result<(StepResult, Vec<String>)> {
         // Look up the tool in the registry
         let tool = self
             .tools
@@ -136,13 +324,28 @@ impl Executor {
         // Execute the tool with the provided parameters
         match tool.execute(tool_call.parameters.clone()).await {
             Ok(result) => {
+                // Collect any non-fatal warnings reported by the tool
+                let warnings = result
+                    .get("warnings")
+                    .and_then(|w| w.as_array())
+                    .map(|warnings| {
+                        warnings
+                            .iter()
+                            .filter_map(|w| {
+                                w.as_str()
+                                    .map(|w| format!("{}: {}", tool_call.tool_name, w))
+                            })
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
                 // Convert the JSON result to a string for the step result
                 let output =
                     serde_json::to_string_pretty(&result).unwrap_or_else(|_| result.to_string());
 
-                Ok(StepResult::success(
-                    format!("tool_call:{}", tool_call.tool_name),
-                    output,
+                Ok((
+                    StepResult::success(format!("tool_call:{}", tool_call.tool_name), output),
+                    warnings,
                 ))
             }
             Err(e) => Err(agent_core::AgentError::ToolExecution {
@@ -160,7 +363,9 @@ mod tests {
     use async_trait::async_trait;
     use planner::{Plan, Step, ToolCall};
     use serde_json::{Value, json};
+    use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::{Arc, Mutex};
+    use std::time::Duration;
 
     // Mock MemoryStore for testing
     #[derive(Clone)]
@@ -556,6 +761,252 @@ mod tests {
         assert_eq!(result.step_results.len(), 2);
     }
 
+    #[tokio::test]
+    async fn test_execute_plan_collects_tool_warnings() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(MockSuccessTool::new(
+            "cached_tool",
+            json!({"result": "ok", "warnings": ["Served from cache"]}),
+        )));
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory);
+
+        let plan = Plan::new(
+            vec![
+                Step::ToolCall(ToolCall::new("cached_tool".to_string(), json!({}))),
+                Step::Response {
+                    text: "Done".to_string(),
+                },
+            ],
+            "Plan with a tool warning".to_string(),
+        );
+
+        let result = executor.execute_plan(plan).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.warnings, vec!["cached_tool: Served from cache"]);
+
+        // Warnings do not leak into the next run
+        let plan = Plan::new(
+            vec![Step::Response {
+                text: "Again".to_string(),
+            }],
+            "Plan without warnings".to_string(),
+        );
+        let result = executor.execute_plan(plan).await.unwrap();
+        assert!(result.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_execute_plan_warns_without_response_step() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(MockSuccessTool::new(
+            "tool1",
+            json!({"result": "output1"}),
+        )));
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory);
+
+        let plan = Plan::new(
+            vec![Step::ToolCall(ToolCall::new("tool1".to_string(), json!({})))],
+            "Plan without explicit response".to_string(),
+        );
+
+        let result = executor.execute_plan(plan).await.unwrap();
+        assert!(result.success);
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("no response step"));
+    }
+
+    #[test]
+    fn test_context_strategies() {
+        let memory_store = MockMemoryStore::new();
+        for i in 1..=4 {
+            memory_store
+                .messages
+                .lock()
+                .unwrap()
+                .push(Message::assistant(format!("Result {}", i)));
+        }
+
+        let executor = Executor::new(ToolRegistry::new(), Box::new(memory_store.clone()))
+            .with_context_strategy(ContextStrategy::RecentCount(2));
+        let context = executor.context();
+        assert_eq!(context.len(), 2);
+        assert_eq!(context[0].content, "Result 3");
+        assert_eq!(context[1].content, "Result 4");
+
+        // The mock answers get_within_budget with every stored message
+        let executor = Executor::new(ToolRegistry::new(), Box::new(memory_store))
+            .with_context_strategy(ContextStrategy::TokenBudget(100));
+        assert_eq!(executor.context().len(), 4);
+    }
+
+    // Mock Tool that sleeps and records how many calls overlap
+    struct MockSlowTool {
+        name: String,
+        running: Arc<AtomicUsize>,
+        peak: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl tools::Tool for MockSlowTool {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn description(&self) -> &str {
+            "Mock tool that takes a while"
+        }
+
+        fn parameters_schema(&self) -> Value {
+            json!({
+                "type": "object",
+                "properties": {}
+            })
+        }
+
+        async fn execute(&self, _params: Value) -> Result<Value> {
+            let now_running = self.running.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now_running, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(json!({"tool": self.name}))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parallel_tool_calls_respect_max_concurrency() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let max_concurrency = 3;
+
+        let mut registry = ToolRegistry::new();
+        let mut steps = Vec::new();
+        for i in 0..12 {
+            let name = format!("slow_tool_{}", i);
+            registry.register(Box::new(MockSlowTool {
+                name: name.clone(),
+                running: running.clone(),
+                peak: peak.clone(),
+            }));
+            steps.push(Step::ToolCall(ToolCall::new(name, json!({}))));
+        }
+        steps.push(Step::Response {
+            text: "All done".to_string(),
+        });
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory)
+            .with_parallel_tool_calls(true)
+            .with_max_concurrency(max_concurrency);
+
+        let plan = Plan::new(steps, "Many independent slow tools".to_string());
+        let result = executor.execute_plan(plan).await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.final_response, "All done");
+        assert_eq!(result.step_results.len(), 13);
+        // Results stay in plan order
+        assert_eq!(result.step_results[0].step_type, "tool_call:slow_tool_0");
+        assert_eq!(result.step_results[11].step_type, "tool_call:slow_tool_11");
+
+        let peak = peak.load(Ordering::SeqCst);
+        assert!(peak <= max_concurrency, "peak concurrency was {}", peak);
+        assert!(peak > 1, "tool calls did not run concurrently");
+    }
+
+    #[tokio::test]
+    async fn test_parallel_tool_calls_stop_after_failed_batch() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(MockSuccessTool::new(
+            "good_tool",
+            json!({"result": "ok"}),
+        )));
+        registry.register(Box::new(MockFailureTool::new("bad_tool")));
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory).with_parallel_tool_calls(true);
+
+        let plan = Plan::new(
+            vec![
+                Step::ToolCall(ToolCall::new("good_tool".to_string(), json!({}))),
+                Step::ToolCall(ToolCall::new("bad_tool".to_string(), json!({}))),
+                Step::Response {
+                    text: "This should not execute".to_string(),
+                },
+            ],
+            "Parallel plan with failure".to_string(),
+        );
+
+        let result = executor.execute_plan(plan).await.unwrap();
+        assert!(!result.success);
+        assert_eq!(result.step_results.len(), 2);
+        assert!(result.step_results[0].success);
+        assert!(!result.step_results[1].success);
+    }
+
+    #[tokio::test]
+    async fn test_parallel_batch_records_outcomes_after_failure() {
+        let mut registry = ToolRegistry::new();
+        registry.register(Box::new(MockFailureTool::new("bad_tool")));
+        registry.register(Box::new(MockSuccessTool::new(
+            "cached_tool",
+            json!({"result": "ok", "warnings": ["Served from cache"]}),
+        )));
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory).with_parallel_tool_calls(true);
+
+        let plan = Plan::new(
+            vec![
+                Step::ToolCall(ToolCall::new("bad_tool".to_string(), json!({}))),
+                Step::ToolCall(ToolCall::new("cached_tool".to_string(), json!({}))),
+                Step::Response {
+                    text: "This should not execute".to_string(),
+                },
+            ],
+            "Parallel plan with early failure".to_string(),
+        );
+
+        let result = executor.execute_plan(plan).await.unwrap();
+        assert!(!result.success);
+        // The whole batch ran, so both outcomes are recorded
+        assert_eq!(result.step_results.len(), 2);
+        assert!(!result.step_results[0].success);
+        assert!(result.step_results[1].success);
+        assert_eq!(result.warnings, vec!["cached_tool: Served from cache"]);
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrency_without_parallel_runs_sequentially() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        let mut registry = ToolRegistry::new();
+        let mut steps = Vec::new();
+        for i in 0..4 {
+            let name = format!("slow_tool_{}", i);
+            registry.register(Box::new(MockSlowTool {
+                name: name.clone(),
+                running: running.clone(),
+                peak: peak.clone(),
+            }));
+            steps.push(Step::ToolCall(ToolCall::new(name, json!({}))));
+        }
+
+        let memory = Box::new(MockMemoryStore::new());
+        let mut executor = Executor::new(registry, memory).with_max_concurrency(8);
+
+        let plan = Plan::new(steps, "Sequential slow tools".to_string());
+        let result = executor.execute_plan(plan).await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.step_results.len(), 4);
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_list_tools() {
         let mut registry = ToolRegistry::new();
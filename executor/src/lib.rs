@@ -3,6 +3,7 @@
 //! This crate provides the execution system that runs plans generated by the planner.
 //! The executor takes a plan with a sequence of steps and executes them in order,
 //! managing tool invocations and storing results in memory for context.
+//! Adjacent tool calls can optionally run concurrently under a concurrency limit.
 //!
 //! # Core Concepts
 //!
@@ -10,6 +11,18 @@
 //! - **ExecutionResult**: The outcome of executing a complete plan
 //! - **StepResult**: The result of executing a single step
 //!
+//! # Parallel Tool Calls
+//!
+//! Off by default. When enabled with `Executor::with_parallel_tool_calls`, every
+//! run of adjacent ToolCall steps is treated as one batch; there is no dependency
+//! or ordering analysis between the calls. This means:
+//!
+//! - Tools with side effects in adjacent steps may run in any order
+//! - A failing call does not stop later calls in the same batch; every outcome
+//!   in the batch is recorded, and execution stops after the batch
+//!
+//! At most `Executor::with_max_concurrency` calls in a batch run at the same time.
+//!
 //! # Example
 //!
 //! ```rust,no_run
@@ -9,6 +9,9 @@ pub struct ExecutionResult {
     pub final_response: String,
     /// Results from each step in the plan
     pub step_results: Vec<StepResult>,
+    /// Non-fatal conditions worth surfacing to the caller (e.g. truncated output)
+    #[serde(default)]
+    pub warnings: Vec<String>,
 }
 
 /// Result of executing a single step
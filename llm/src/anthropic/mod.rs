@@ -88,25 +88,9 @@ impl AnthropicProvider {
 
         (system_message, anthropic_messages)
     }
-}
-
-#[async_trait]
-impl LLMProvider for AnthropicProvider {
-    async fn send_message(&self, messages: &[Message]) -> Result<String> {
-        // Convert framework messages to Anthropic format, separating system messages
-        let (system, anthropic_messages) = Self::convert_messages(messages);
-
-        // Build the request
-        let request = MessagesRequest {
-            model: self.model.clone(),
-            messages: anthropic_messages,
-            system,
-            temperature: self.temperature,
-            max_tokens: self.max_tokens,
-        };
-
-        let url = format!("{}/messages", self.base_url);
 
+    /// Build the authentication, version, and content-type headers for API requests
+    fn headers(&self) -> Result<reqwest::header::HeaderMap> {
         let mut headers = reqwest::header::HeaderMap::new();
         headers.insert(
             "x-api-key",
@@ -126,10 +110,30 @@ impl LLMProvider for AnthropicProvider {
                 .parse()
                 .map_err(|e| AgentError::LLMProvider(format!("Invalid header value: {}", e)))?,
         );
+        Ok(headers)
+    }
+}
+
+#[async_trait]
+impl LLMProvider for AnthropicProvider {
+    async fn send_message(&self, messages: &[Message]) -> Result<String> {
+        // Convert framework messages to Anthropic format, separating system messages
+        let (system, anthropic_messages) = Self::convert_messages(messages);
+
+        // Build the request
+        let request = MessagesRequest {
+            model: self.model.clone(),
+            messages: anthropic_messages,
+            system,
+            temperature: self.temperature,
+            max_tokens: self.max_tokens,
+        };
+
+        let url = format!("{}/messages", self.base_url);
 
         let messages_response: MessagesResponse = self
             .client
-            .post_json_with_headers(&url, &request, headers)
+            .post_json_with_headers(&url, &request, self.headers()?)
             .await?;
 
         // Extract the response text from content[0].text
@@ -141,4 +145,13 @@ impl LLMProvider for AnthropicProvider {
                 AgentError::LLMProvider("Anthropic response contained no content".to_string())
             })
     }
+
+    async fn health_check(&self) -> Result<()> {
+        // Listing models checks reachability and the API key without generating text
+        let url = format!("{}/models", self.base_url);
+        self.client
+            .get_json_with_headers::<serde_json::Value>(&url, self.headers()?)
+            .await
+            .map(|_| ())
+    }
 }
@@ -3,6 +3,17 @@ use config::LLMConfig;
 
 use crate::{LLMProvider, anthropic::AnthropicProvider, ollama::OllamaProvider, openai::OpenAIProvider};
 
+/// Names of the providers that `create_provider` knows how to build
+const SUPPORTED_PROVIDERS: &[&str] = &["openai", "anthropic", "ollama"];
+
+/// List the provider names accepted by `create_provider`
+///
+/// # Returns
+/// * `Vec<&'static str>` - Provider names, in the order they are documented
+pub fn supported_providers() -> Vec<&'static str> {
+    SUPPORTED_PROVIDERS.to_vec()
+}
+
 /// Create an LLM provider instance from configuration
 ///
 /// # Arguments
@@ -35,8 +46,9 @@ pub fn create_provider(config: &LLMConfig) -> Result<Box<dyn LLMProvider>> {
             Ok(Box::new(provider))
         }
         _ => Err(AgentError::Config(format!(
-            "Unknown LLM provider: '{}'. Supported providers: openai, anthropic, ollama",
-            config.provider
+            "Unknown LLM provider: '{}'. Supported providers: {}",
+            config.provider,
+            SUPPORTED_PROVIDERS.join(", ")
         ))),
     }
 }
@@ -54,6 +66,7 @@ mod tests {
             base_url: None,
             temperature: 0.7,
             max_tokens: 2000,
+            allowed_models: Vec::new(),
         };
 
         let result = create_provider(&config);
@@ -69,6 +82,7 @@ mod tests {
             base_url: None,
             temperature: 0.7,
             max_tokens: 2000,
+            allowed_models: Vec::new(),
         };
 
         let result = create_provider(&config);
@@ -84,6 +98,7 @@ mod tests {
             base_url: None,
             temperature: 0.7,
             max_tokens: 2000,
+            allowed_models: Vec::new(),
         };
 
         let result = create_provider(&config);
@@ -95,6 +110,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_supported_providers() {
+        let providers = supported_providers();
+        assert!(providers.contains(&"openai"));
+        assert!(providers.contains(&"anthropic"));
+        assert!(providers.contains(&"ollama"));
+    }
+
+    #[test]
+    fn test_create_provider_accepts_every_supported_provider() {
+        for name in supported_providers() {
+            let config = LLMConfig {
+                provider: name.to_string(),
+                model: "some-model".to_string(),
+                api_key: "test-key".to_string(),
+                base_url: None,
+                temperature: 0.7,
+                max_tokens: 2000,
+                allowed_models: Vec::new(),
+            };
+
+            assert!(
+                create_provider(&config).is_ok(),
+                "supported provider '{}' was rejected by create_provider",
+                name
+            );
+        }
+    }
+
     #[test]
     fn test_create_ollama_provider() {
         let config = LLMConfig {
@@ -104,6 +148,7 @@ mod tests {
             base_url: Some("http://localhost:11434".to_string()),
             temperature: 0.7,
             max_tokens: 2000,
+            allowed_models: Vec::new(),
         };
 
         let result = create_provider(&config);
@@ -26,6 +26,7 @@
 //!     base_url: None,
 //!     temperature: 0.7,
 //!     max_tokens: 2000,
+//!     allowed_models: Vec::new(),
 //! };
 //!
 //! let provider = create_provider(&config)?;
@@ -40,7 +41,7 @@ pub mod openai;
 mod provider;
 
 pub use anthropic::AnthropicProvider;
-pub use factory::create_provider;
+pub use factory::{create_provider, supported_providers};
 pub use ollama::OllamaProvider;
 pub use openai::OpenAIProvider;
 pub use provider::LLMProvider;
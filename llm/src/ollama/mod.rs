@@ -27,6 +27,7 @@ pub use types::{ChatRequest, ChatResponse, OllamaMessage};
 ///     base_url: Some("http://localhost:11434".to_string()),
 ///     temperature: 0.7,
 ///     max_tokens: 2000,
+///     allowed_models: Vec::new(),
 /// };
 ///
 /// let provider = OllamaProvider::new(&config).unwrap();
@@ -121,4 +122,13 @@ impl LLMProvider for OllamaProvider {
 
         Ok(response.message.content)
     }
+
+    async fn health_check(&self) -> Result<()> {
+        // Listing local models checks the server is up without loading a model
+        let url = format!("{}/api/tags", self.base_url);
+        self.client
+            .get_json_with_headers::<serde_json::Value>(&url, reqwest::header::HeaderMap::new())
+            .await
+            .map(|_| ())
+    }
 }
@@ -7,7 +7,7 @@ use config::LLMConfig;
 
 use crate::LLMProvider;
 
-pub use types::{ChatCompletionRequest, ChatCompletionResponse, OpenAIMessage};
+pub use types::{ChatCompletionRequest, ChatCompletionResponse, OpenAIMessage, ResponseMessage};
 
 /// OpenAI LLM provider implementation
 pub struct OpenAIProvider {
@@ -59,6 +59,26 @@ impl OpenAIProvider {
     fn convert_messages(messages: &[Message]) -> Vec<types::OpenAIMessage> {
         messages.iter().map(Self::convert_message).collect()
     }
+
+    /// Build the authentication and content-type headers for API requests
+    fn headers(&self) -> Result<reqwest::header::HeaderMap> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "Authorization",
+            format!("Bearer {}", self.api_key)
+                .parse()
+                .map_err(|e| {
+                    AgentError::LLMProvider(format!("Invalid API key format: {}", e))
+                })?,
+        );
+        headers.insert(
+            "Content-Type",
+            "application/json"
+                .parse()
+                .map_err(|e| AgentError::LLMProvider(format!("Invalid header value: {}", e)))?,
+        );
+        Ok(headers)
+    }
 }
 
 #[async_trait]
@@ -77,34 +97,54 @@ impl LLMProvider for OpenAIProvider {
 
         let url = format!("{}/chat/completions", self.base_url);
 
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            "Authorization",
-            format!("Bearer {}", self.api_key)
-                .parse()
-                .map_err(|e| {
-                    AgentError::LLMProvider(format!("Invalid API key format: {}", e))
-                })?,
-        );
-        headers.insert(
-            "Content-Type",
-            "application/json"
-                .parse()
-                .map_err(|e| AgentError::LLMProvider(format!("Invalid header value: {}", e)))?,
-        );
-
         let completion: ChatCompletionResponse = self
             .client
-            .post_json_with_headers(&url, &request, headers)
+            .post_json_with_headers(&url, &request, self.headers()?)
             .await?;
 
         // Extract the response text from choices[0].message.content
-        completion
-            .choices
-            .first()
-            .map(|choice| choice.message.content.clone())
-            .ok_or_else(|| {
-                AgentError::LLMProvider("OpenAI response contained no choices".to_string())
-            })
+        let choice = completion.choices.into_iter().next().ok_or_else(|| {
+            AgentError::LLMProvider("OpenAI response contained no choices".to_string())
+        })?;
+
+        // Surface content filtering, truncation, and refusals instead of returning partial text
+        match choice.finish_reason.as_deref() {
+            Some("content_filter") => {
+                return Err(AgentError::LLMFinish {
+                    reason: "content_filter".to_string(),
+                    message: "OpenAI response was blocked by the content filter".to_string(),
+                });
+            }
+            Some("length") => {
+                return Err(AgentError::LLMFinish {
+                    reason: "length".to_string(),
+                    message: format!(
+                        "OpenAI response was truncated at max_tokens={}",
+                        self.max_tokens
+                    ),
+                });
+            }
+            _ => {}
+        }
+
+        if let Some(refusal) = choice.message.refusal {
+            return Err(AgentError::LLMFinish {
+                reason: "refusal".to_string(),
+                message: format!("OpenAI model refused the request: {}", refusal),
+            });
+        }
+
+        choice.message.content.ok_or_else(|| {
+            AgentError::LLMProvider("OpenAI response contained no content".to_string())
+        })
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        // Listing models checks reachability and the API key without generating text
+        let url = format!("{}/models", self.base_url);
+        self.client
+            .get_json_with_headers::<serde_json::Value>(&url, self.headers()?)
+            .await
+            .map(|_| ())
     }
 }
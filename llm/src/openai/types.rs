@@ -53,7 +53,22 @@ pub struct Choice {
     /// Index of this choice in the choices array
     pub index: u32,
     /// The generated message
-    pub message: OpenAIMessage,
-    /// Reason why the model stopped generating (e.g., "stop", "length")
+    pub message: ResponseMessage,
+    /// Reason why the model stopped generating (e.g., "stop", "length", "content_filter")
     pub finish_reason: Option<String>,
 }
+
+/// Assistant message returned in a completion choice.
+///
+/// Unlike `OpenAIMessage`, the content may be null when the model refuses.
+#[derive(Debug, Deserialize)]
+pub struct ResponseMessage {
+    /// The role of the message sender (always "assistant")
+    pub role: String,
+    /// The generated text, absent on refusals
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Explanation provided by the model when it refuses the request
+    #[serde(default)]
+    pub refusal: Option<String>,
+}
@@ -15,4 +15,17 @@ pub trait LLMProvider: Send + Sync {
     /// # Returns
     /// * `Result<String>` - The LLM's response text or an error
     async fn send_message(&self, messages: &[Message]) -> Result<String>;
+
+    /// Check that the provider is reachable and accepts requests
+    ///
+    /// The default implementation sends a minimal single-message request, which
+    /// is a real (and, for hosted providers, billed) completion. Providers should
+    /// override this with a cheaper call; the built-in providers list models
+    /// (`GET /models`, or `GET /api/tags` for Ollama) instead of generating text.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Ok if the provider responded successfully
+    async fn health_check(&self) -> Result<()> {
+        self.send_message(&[Message::user("ping")]).await.map(|_| ())
+    }
 }
@@ -0,0 +1,125 @@
+//! Context selection strategies.
+//!
+//! This module defines `ContextStrategy`, which decides which `MemoryStore`
+//! getter is used when a component assembles conversation context.
+
+use crate::MemoryStore;
+use agent_core::Message;
+
+/// Token budget used by `ContextStrategy::default()`, matching the
+/// default `MemoryConfig.token_budget`
+const DEFAULT_TOKEN_BUDGET: usize = 4000;
+
+/// Strategy for selecting conversation context from a memory store
+///
+/// # Examples
+///
+/// ```
+/// use memory::{ContextStrategy, InMemoryStore, MemoryStore};
+/// use agent_core::Message;
+///
+/// let mut store = InMemoryStore::new();
+/// store.add_message(Message::user("First"));
+/// store.add_message(Message::assistant("Second"));
+///
+/// let context = ContextStrategy::RecentCount(1).select(&store);
+/// assert_eq!(context.len(), 1);
+/// assert_eq!(context[0].content, "Second");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContextStrategy {
+    /// Use a fixed number of the most recent messages (`MemoryStore::get_recent`)
+    RecentCount(usize),
+    /// Use as many recent messages as fit in a token budget (`MemoryStore::get_within_budget`)
+    TokenBudget(usize),
+}
+
+impl ContextStrategy {
+    /// Select context messages from the store, in chronological order
+    pub fn select(&self, store: &dyn MemoryStore) -> Vec<Message> {
+        match *self {
+            ContextStrategy::RecentCount(limit) => store.get_recent(limit),
+            ContextStrategy::TokenBudget(budget) => store.get_within_budget(budget),
+        }
+    }
+}
+
+impl Default for ContextStrategy {
+    fn default() -> Self {
+        ContextStrategy::TokenBudget(DEFAULT_TOKEN_BUDGET)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mock store where every message costs a fixed number of tokens
+    struct MockMemoryStore {
+        messages: Vec<Message>,
+    }
+
+    const TOKENS_PER_MESSAGE: usize = 10;
+
+    impl MemoryStore for MockMemoryStore {
+        fn add_message(&mut self, message: Message) {
+            self.messages.push(message);
+        }
+
+        fn get_recent(&self, limit: usize) -> Vec<Message> {
+            self.messages
+                .iter()
+                .rev()
+                .take(limit)
+                .rev()
+                .cloned()
+                .collect()
+        }
+
+        fn get_within_budget(&self, token_budget: usize) -> Vec<Message> {
+            self.get_recent(token_budget / TOKENS_PER_MESSAGE)
+        }
+
+        fn clear(&mut self) {
+            self.messages.clear();
+        }
+    }
+
+    fn populated_store() -> MockMemoryStore {
+        let mut store = MockMemoryStore {
+            messages: Vec::new(),
+        };
+        for i in 1..=5 {
+            store.add_message(Message::user(format!("Message {}", i)));
+        }
+        store
+    }
+
+    #[test]
+    fn test_recent_count_strategy() {
+        let store = populated_store();
+
+        let context = ContextStrategy::RecentCount(2).select(&store);
+        assert_eq!(context.len(), 2);
+        assert_eq!(context[0].content, "Message 4");
+        assert_eq!(context[1].content, "Message 5");
+    }
+
+    #[test]
+    fn test_token_budget_strategy() {
+        let store = populated_store();
+
+        let context = ContextStrategy::TokenBudget(3 * TOKENS_PER_MESSAGE).select(&store);
+        assert_eq!(context.len(), 3);
+        assert_eq!(context[0].content, "Message 3");
+        assert_eq!(context[2].content, "Message 5");
+    }
+
+    #[test]
+    fn test_default_strategy() {
+        assert_eq!(
+            ContextStrategy::default(),
+            ContextStrategy::TokenBudget(DEFAULT_TOKEN_BUDGET)
+        );
+    }
+}
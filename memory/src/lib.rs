@@ -7,6 +7,7 @@
 //! - `InMemoryStore` implementation using Vec for MVP
 //! - Token counting functionality using tiktoken-rs for OpenAI models
 //! - `ConversationHistory` wrapper with convenience methods
+//! - `ContextStrategy` for choosing how context is pulled from a store
 //!
 //! # Examples
 //!
@@ -23,11 +24,13 @@
 //! assert_eq!(recent.len(), 2);
 //! ```
 
+mod context;
 mod history;
 mod in_memory;
 mod store;
 mod token_counter;
 
+pub use context::ContextStrategy;
 pub use history::ConversationHistory;
 pub use in_memory::InMemoryStore;
 pub use store::MemoryStore;
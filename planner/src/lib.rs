@@ -17,7 +17,8 @@
 //!
 //! 1. **System Prompt Generation**: Creates a detailed prompt that includes available
 //!    tools, their descriptions, parameter schemas, and instructions for generating
-//!    valid JSON plans.
+//!    valid JSON plans. With `Planner::with_lazy_schemas(true)`, the LLM first picks
+//!    tools from names and descriptions, and only the chosen tools' schemas are sent.
 //!
 //! 2. **LLM Invocation**: Sends the system prompt and user goal to the LLM provider
 //!    to generate a plan using reasoning capabilities.
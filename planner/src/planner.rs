@@ -1,15 +1,24 @@
 use crate::types::{Plan, Step};
 use agent_core::{Message, Result};
+use memory::ContextStrategy;
+use serde::Deserialize;
 use tools::{ToolInfo, ToolRegistry};
 
+/// Tool selection returned by the LLM in the first phase of lazy-schema planning.
+#[derive(Debug, Deserialize)]
+struct ToolSelection {
+    tools: Vec<String>,
+}
+
 /// The Planner orchestrates plan generation using LLM reasoning.
 ///
 /// It takes a user goal and available tools, then uses the LLM to generate
 /// a structured plan with steps that the executor can run.
 pub struct Planner {
     llm: Box<dyn llm::LLMProvider>,
-    #[allow(dead_code)] // Reserved for future use to provide conversation context
     memory: Box<dyn memory::MemoryStore>,
+    context_strategy: ContextStrategy,
+    lazy_schemas: bool,
 }
 
 impl Planner {
@@ -22,7 +31,92 @@ impl Planner {
     /// # Returns
     /// A new Planner instance
     pub fn new(llm: Box<dyn llm::LLMProvider>, memory: Box<dyn memory::MemoryStore>) -> Self {
-        Self { llm, memory }
+        Self {
+            llm,
+            memory,
+            context_strategy: ContextStrategy::default(),
+            lazy_schemas: false,
+        }
+    }
+
+    /// Sets the strategy used to pull conversation context from memory.
+    ///
+    /// # Arguments
+    /// * `strategy` - Which memory getter to use when assembling context
+    ///
+    /// # Returns
+    /// The Planner with the strategy applied
+    pub fn with_context_strategy(mut self, strategy: ContextStrategy) -> Self {
+        self.context_strategy = strategy;
+        self
+    }
+
+    /// Returns the strategy used to pull conversation context from memory.
+    pub fn context_strategy(&self) -> ContextStrategy {
+        self.context_strategy
+    }
+
+    /// Returns the memory store that planning context is selected from.
+    ///
+    /// Callers record the conversation here so later plans can see it.
+    pub fn memory_mut(&mut self) -> &mut dyn memory::MemoryStore {
+        self.memory.as_mut()
+    }
+
+    /// Enables or disables two-phase planning with lazily loaded tool schemas.
+    ///
+    /// When enabled, the LLM first sees only tool names and descriptions and
+    /// picks the tools it needs. The planning call then includes parameter
+    /// schemas for the selected tools only, which keeps prompts small for
+    /// large registries.
+    ///
+    /// # Arguments
+    /// * `enabled` - Whether to use lazy schema loading
+    ///
+    /// # Returns
+    /// The Planner with the setting applied
+    pub fn with_lazy_schemas(mut self, enabled: bool) -> Self {
+        self.lazy_schemas = enabled;
+        self
+    }
+
+    /// Builds a system prompt asking the LLM to pick tools for a goal.
+    ///
+    /// Only tool names and descriptions are included; parameter schemas are
+    /// omitted so that the prompt stays small for large registries.
+    ///
+    /// # Arguments
+    /// * `available_tools` - List of tools the agent can use
+    ///
+    /// # Returns
+    /// A formatted system prompt string
+    pub fn build_tool_selection_prompt(&self, available_tools: &[ToolInfo]) -> String {
+        let mut prompt = String::from(
+            "You are an AI planning assistant. Before planning, select the tools needed \
+            to achieve the user's goal. You must respond with a valid JSON object following \
+            this exact format:\n\n\
+            {\n  \
+              \"tools\": [\"tool_name\", ...]\n\
+            }\n\n\
+            Return an empty list if no tools are needed.\n\n\
+            Available tools:\n\n",
+        );
+
+        for tool in available_tools {
+            prompt.push_str(&format!("- **{}**: {}\n", tool.name, tool.description));
+        }
+
+        prompt.push_str("\nRemember: Respond ONLY with valid JSON. Do not include any other text.");
+
+        prompt
+    }
+
+    /// Checks that the planner's LLM provider is reachable.
+    ///
+    /// # Returns
+    /// * `Result<()>` - Ok if the provider health check succeeded
+    pub async fn health_check(&self) -> Result<()> {
+        self.llm.health_check().await
     }
 
     /// Builds a system prompt that instructs the LLM on how to generate plans.
@@ -84,8 +178,10 @@ impl Planner {
     /// Creates a plan for achieving the given goal.
     ///
     /// This method:
-    /// 1. Builds a system prompt with available tools
-    /// 2. Creates a message array with the system prompt and user goal
+    /// 1. Builds a system prompt with available tools (with lazy schemas enabled,
+    ///    the LLM first selects tools and only their schemas are included)
+    /// 2. Creates a message array with the system prompt, conversation context
+    ///    selected by the configured `ContextStrategy`, and the user goal
     /// 3. Calls the LLM to generate a plan
     /// 4. Parses the LLM response into a structured Plan
     ///
@@ -96,19 +192,69 @@ impl Planner {
     /// # Returns
     /// * `Result<Plan>` - The generated plan or an error
     pub async fn create_plan(&self, goal: &str, available_tools: &[ToolInfo]) -> Result<Plan> {
-        // Build the system prompt with available tools
-        let system_prompt = self.build_system_prompt(available_tools);
+        // With lazy schemas, let the LLM pick the tools before sending any schemas
+        let selected_tools;
+        let planning_tools = if self.lazy_schemas && !available_tools.is_empty() {
+            selected_tools = self.select_tools(goal, available_tools).await?;
+            selected_tools.as_slice()
+        } else {
+            available_tools
+        };
 
-        // Create messages array with system prompt and user goal
-        let messages = vec![Message::system(&system_prompt), Message::user(goal)];
+        // Build the system prompt with available tools
+        let system_prompt = self.build_system_prompt(planning_tools);
 
         // Call LLM to generate plan
+        let messages = self.build_messages(&system_prompt, goal);
         let response = self.llm.send_message(&messages).await?;
 
         // Parse the response into a Plan
         self.parse_plan(&response)
     }
 
+    /// Asks the LLM which tools it needs for the goal (first phase of lazy-schema planning).
+    ///
+    /// Tool names that do not match an available tool are ignored.
+    ///
+    /// # Arguments
+    /// * `goal` - The user's goal or request
+    /// * `available_tools` - List of tools the agent can use
+    ///
+    /// # Returns
+    /// * `Result<Vec<ToolInfo>>` - The selected tools, with full schemas
+    async fn select_tools(
+        &self,
+        goal: &str,
+        available_tools: &[ToolInfo],
+    ) -> Result<Vec<ToolInfo>> {
+        let system_prompt = self.build_tool_selection_prompt(available_tools);
+        let messages = self.build_messages(&system_prompt, goal);
+        let response = self.llm.send_message(&messages).await?;
+
+        let json_str = self.extract_json(&response)?;
+        let selection: ToolSelection = serde_json::from_str(json_str).map_err(|e| {
+            agent_core::AgentError::Planning(format!(
+                "Failed to parse tool selection JSON: {}. Response was: {}",
+                e, json_str
+            ))
+        })?;
+
+        Ok(available_tools
+            .iter()
+            .filter(|tool| selection.tools.contains(&tool.name))
+            .cloned()
+            .collect())
+    }
+
+    /// Creates a message array with the system prompt, conversation context
+    /// selected by the configured `ContextStrategy`, and the user goal.
+    fn build_messages(&self, system_prompt: &str, goal: &str) -> Vec<Message> {
+        let mut messages = vec![Message::system(system_prompt)];
+        messages.extend(self.context_strategy.select(self.memory.as_ref()));
+        messages.push(Message::user(goal));
+        messages
+    }
+
     /// Parses an LLM response into a structured Plan.
     ///
     /// The response is expected to be a JSON object with:
@@ -263,6 +409,7 @@ mod tests {
     struct MockLLM {
         responses: Vec<String>,
         call_index: std::sync::Arc<std::sync::Mutex<usize>>,
+        received: std::sync::Arc<std::sync::Mutex<Vec<Vec<Message>>>>,
     }
 
     impl MockLLM {
@@ -270,13 +417,15 @@ mod tests {
             Self {
                 responses,
                 call_index: std::sync::Arc::new(std::sync::Mutex::new(0)),
+                received: std::sync::Arc::new(std::sync::Mutex::new(Vec::new())),
             }
         }
     }
 
     #[async_trait]
     impl llm::LLMProvider for MockLLM {
-        async fn send_message(&self, _messages: &[Message]) -> Result<String> {
+        async fn send_message(&self, messages: &[Message]) -> Result<String> {
+            self.received.lock().unwrap().push(messages.to_vec());
             let mut index = self.call_index.lock().unwrap();
             let response = self
                 .responses
@@ -819,6 +968,121 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_create_plan_with_context_strategies() {
+        let plan_json = r#"{"reasoning": "test", "steps": []}"#;
+        let history = vec![
+            Message::user("What is 2 + 2?"),
+            Message::assistant("4"),
+            Message::user("And times 3?"),
+        ];
+
+        // RecentCount pulls only the newest N messages
+        let mock_llm = MockLLM::new(vec![plan_json.to_string()]);
+        let received = mock_llm.received.clone();
+        let memory = MockMemoryStore {
+            messages: history.clone(),
+        };
+        let planner = Planner::new(Box::new(mock_llm), Box::new(memory))
+            .with_context_strategy(ContextStrategy::RecentCount(1));
+
+        planner.create_plan("Compute it", &[]).await.unwrap();
+
+        let sent = received.lock().unwrap()[0].clone();
+        let contents: Vec<&str> = sent.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(sent.len(), 3);
+        assert_eq!(contents[1..], ["And times 3?", "Compute it"]);
+
+        // TokenBudget delegates to get_within_budget, which the mock answers with everything
+        let mock_llm = MockLLM::new(vec![plan_json.to_string()]);
+        let received = mock_llm.received.clone();
+        let memory = MockMemoryStore { messages: history };
+        let planner = Planner::new(Box::new(mock_llm), Box::new(memory))
+            .with_context_strategy(ContextStrategy::TokenBudget(100));
+
+        planner.create_plan("Compute it", &[]).await.unwrap();
+
+        let sent = received.lock().unwrap()[0].clone();
+        let contents: Vec<&str> = sent.iter().map(|m| m.content.as_str()).collect();
+        assert_eq!(
+            contents[1..],
+            ["What is 2 + 2?", "4", "And times 3?", "Compute it"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_plan_with_lazy_schemas() {
+        let tools: Vec<ToolInfo> = (0..200)
+            .map(|i| ToolInfo {
+                name: format!("tool_{}", i),
+                description: format!("Mock tool number {}", i),
+                parameters_schema: json!({
+                    "type": "object",
+                    "properties": {
+                        format!("param_for_tool_{}", i): {"type": "string"}
+                    }
+                }),
+            })
+            .collect();
+
+        let selection_json = r#"{"tools": ["tool_42", "not_a_tool"]}"#;
+        let plan_json = r#"{
+            "reasoning": "Use tool_42",
+            "steps": [
+                {"type": "tool_call", "tool_name": "tool_42", "parameters": {"param_for_tool_42": "x"}},
+                {"type": "response", "text": "Done"}
+            ]
+        }"#;
+
+        let mock_llm = MockLLM::new(vec![selection_json.to_string(), plan_json.to_string()]);
+        let received = mock_llm.received.clone();
+        let planner = Planner::new(Box::new(mock_llm), Box::new(MockMemoryStore::new()))
+            .with_lazy_schemas(true);
+
+        let plan = planner
+            .create_plan("Do the tool_42 thing", &tools)
+            .await
+            .expect("Should create plan");
+        assert_eq!(plan.steps.len(), 2);
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+
+        // First prompt lists every tool but omits schemas
+        let selection_prompt = &received[0][0].content;
+        assert!(selection_prompt.contains("**tool_0**"));
+        assert!(selection_prompt.contains("**tool_199**"));
+        assert!(!selection_prompt.contains("Parameters schema"));
+        assert!(!selection_prompt.contains("param_for_tool_"));
+
+        // Second prompt includes only the selected tool's schema
+        let planning_prompt = &received[1][0].content;
+        assert!(planning_prompt.contains("**tool_42**"));
+        assert!(planning_prompt.contains("param_for_tool_42"));
+        assert_eq!(planning_prompt.matches("param_for_tool_").count(), 1);
+        assert!(!planning_prompt.contains("**tool_0**"));
+    }
+
+    #[tokio::test]
+    async fn test_create_plan_without_lazy_schemas_makes_single_call() {
+        let tools = vec![ToolInfo {
+            name: "calculator".to_string(),
+            description: "Performs arithmetic".to_string(),
+            parameters_schema: json!({"type": "object"}),
+        }];
+        let plan_json = r#"{"reasoning": "test", "steps": []}"#;
+
+        let mock_llm = MockLLM::new(vec![plan_json.to_string()]);
+        let received = mock_llm.received.clone();
+        let planner = Planner::new(Box::new(mock_llm), Box::new(MockMemoryStore::new()));
+
+        planner.create_plan("Add numbers", &tools).await.unwrap();
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        assert!(received[0][0].content.contains("Parameters schema"));
+    }
+
     // Helper function to create a test planner
     fn create_test_planner(responses: Vec<String>) -> Planner {
         let mock_llm = Box::new(MockLLM::new(responses));
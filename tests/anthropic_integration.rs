@@ -11,7 +11,7 @@ use agent_core::Message;
 use config::LLMConfig;
 use llm::{AnthropicProvider, LLMProvider};
 use wiremock::{
-    matchers::{method, path},
+    matchers::{header, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -24,6 +24,7 @@ async fn create_test_config(mock_server: &MockServer) -> LLMConfig {
         base_url: Some(mock_server.uri()),
         temperature: 0.7,
         max_tokens: 100,
+        allowed_models: Vec::new(),
     }
 }
 
@@ -406,3 +407,36 @@ async fn test_anthropic_only_system_message() {
         "Request with only system message should fail"
     );
 }
+
+#[tokio::test]
+async fn test_anthropic_health_check_lists_models() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    // The health check lists models instead of requesting a completion
+    Mock::given(method("GET"))
+        .and(path("/models"))
+        .and(header("x-api-key", "sk-ant-test-key"))
+        .and(header("anthropic-version", "2023-06-01"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [{"id": "claude-3-haiku-20240307", "type": "model"}],
+            "has_more": false
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(anthropic_success_response("Hi")))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = AnthropicProvider::new(&config).expect("Failed to create Anthropic provider");
+
+    provider
+        .health_check()
+        .await
+        .expect("Health check should succeed when models can be listed");
+}
@@ -7,11 +7,11 @@
 //! - 3.2: LLM provider implementation for OpenAI
 //! - 3.3: Error handling for API failures
 
-use agent_core::Message;
+use agent_core::{AgentError, Message};
 use config::LLMConfig;
 use llm::{LLMProvider, OpenAIProvider};
 use wiremock::{
-    matchers::{method, path},
+    matchers::{header, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -24,6 +24,7 @@ async fn create_test_config(mock_server: &MockServer) -> LLMConfig {
         base_url: Some(mock_server.uri()),
         temperature: 0.7,
         max_tokens: 100,
+        allowed_models: Vec::new(),
     }
 }
 
@@ -50,6 +51,13 @@ fn openai_success_response(content: &str) -> serde_json::Value {
     })
 }
 
+/// Helper to create an OpenAI response with a specific finish reason
+fn openai_finish_response(content: &str, finish_reason: &str) -> serde_json::Value {
+    let mut response = openai_success_response(content);
+    response["choices"][0]["finish_reason"] = serde_json::json!(finish_reason);
+    response
+}
+
 #[tokio::test]
 async fn test_openai_successful_message_sending() {
     // Start mock server
@@ -339,3 +347,155 @@ async fn test_openai_temperature_setting() {
     // Verify we got a response
     assert!(!response.is_empty(), "Response should not be empty");
 }
+
+#[tokio::test]
+async fn test_openai_content_filter_finish_reason() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    // Set up mock response that was cut off by the content filter
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(openai_finish_response("", "content_filter")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = OpenAIProvider::new(&config).expect("Failed to create OpenAI provider");
+
+    let messages = vec![Message::user("Tell me something disallowed.")];
+    let result = provider.send_message(&messages).await;
+
+    // Verify the filter is reported rather than an empty string
+    match result {
+        Err(AgentError::LLMFinish { reason, .. }) => assert_eq!(reason, "content_filter"),
+        other => panic!("Expected LLMFinish error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_openai_length_finish_reason() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    // Set up mock response that hit the token limit
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(openai_finish_response("Once upon a time", "length")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = OpenAIProvider::new(&config).expect("Failed to create OpenAI provider");
+
+    let messages = vec![Message::user("Write a long story about a dragon.")];
+    let result = provider.send_message(&messages).await;
+
+    // Verify truncation is distinct from the content filter error
+    match result {
+        Err(AgentError::LLMFinish { reason, message }) => {
+            assert_eq!(reason, "length");
+            assert!(message.contains("max_tokens=100"), "got: {}", message);
+        }
+        other => panic!("Expected LLMFinish error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_openai_refusal() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    // Set up mock response where the model refuses and returns null content
+    let mut response = openai_success_response("");
+    response["choices"][0]["message"] = serde_json::json!({
+        "role": "assistant",
+        "content": null,
+        "refusal": "I can't help with that request."
+    });
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response))
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = OpenAIProvider::new(&config).expect("Failed to create OpenAI provider");
+
+    let messages = vec![Message::user("Tell me something disallowed.")];
+    let result = provider.send_message(&messages).await;
+
+    // Verify the refusal text is surfaced instead of a deserialization error
+    match result {
+        Err(AgentError::LLMFinish { reason, message }) => {
+            assert_eq!(reason, "refusal");
+            assert!(
+                message.contains("I can't help with that request."),
+                "Error should include the refusal, got: {}",
+                message
+            );
+        }
+        other => panic!("Expected LLMFinish error, got: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_openai_health_check_lists_models() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    // The health check lists models instead of requesting a completion
+    Mock::given(method("GET"))
+        .and(path("/models"))
+        .and(header("Authorization", "Bearer sk-test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "object": "list",
+            "data": [{"id": "gpt-3.5-turbo", "object": "model"}]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(openai_success_response("Hi")))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = OpenAIProvider::new(&config).expect("Failed to create OpenAI provider");
+
+    provider
+        .health_check()
+        .await
+        .expect("Health check should succeed when models can be listed");
+}
+
+#[tokio::test]
+async fn test_openai_health_check_invalid_api_key() {
+    // Start mock server
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/models"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+            "error": {"message": "Incorrect API key provided", "type": "invalid_request_error"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let config = create_test_config(&mock_server).await;
+    let provider = OpenAIProvider::new(&config).expect("Failed to create OpenAI provider");
+
+    let result = provider.health_check().await;
+    match result {
+        Err(AgentError::LLMProvider(msg)) => assert!(msg.contains("401"), "got: {}", msg),
+        other => panic!("Expected LLMProvider error, got: {:?}", other),
+    }
+}
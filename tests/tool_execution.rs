@@ -619,3 +619,55 @@ async fn test_complex_multi_tool_workflow() {
     assert!(result.step_results[3].output.contains("42"));
     assert!(result.step_results[5].output.contains("meaning of life"));
 }
+
+#[tokio::test]
+async fn test_truncated_file_and_cache_hit_produce_warnings() {
+    // Create a file larger than the reader's size cap
+    let mut temp_file = NamedTempFile::new().unwrap();
+    temp_file.write_all(b"0123456789abcdef").unwrap();
+    temp_file.flush().unwrap();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let mut registry = ToolRegistry::new();
+    registry.register(Box::new(FileReader::new().with_max_bytes(8)));
+    registry.register(Box::new(WebSearchStub::new()));
+
+    let memory = Box::new(MockMemoryStore::new());
+    let mut executor = Executor::new(registry, memory);
+
+    // The second identical search is served from the cache
+    let plan = Plan::new(
+        vec![
+            Step::ToolCall(ToolCall::new(
+                "file_reader".to_string(),
+                json!({"file_path": file_path}),
+            )),
+            Step::ToolCall(ToolCall::new(
+                "web_search".to_string(),
+                json!({"query": "rust"}),
+            )),
+            Step::ToolCall(ToolCall::new(
+                "web_search".to_string(),
+                json!({"query": "rust"}),
+            )),
+            Step::Response {
+                text: "Done".to_string(),
+            },
+        ],
+        "Read a large file and repeat a search".to_string(),
+    );
+
+    let result = executor.execute_plan(plan).await.unwrap();
+
+    // Warnings are surfaced without failing the run
+    assert!(result.success);
+    assert!(result.step_results.iter().all(|r| r.success));
+    assert_eq!(result.final_response, "Done");
+    assert_eq!(
+        result.warnings,
+        vec![
+            "file_reader: File truncated to 8 of 16 bytes".to_string(),
+            "web_search: Served cached results for 'rust'".to_string(),
+        ]
+    );
+}
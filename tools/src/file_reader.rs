@@ -2,16 +2,49 @@ use crate::tool::Tool;
 use agent_core::{AgentError, Result};
 use async_trait::async_trait;
 use serde_json::{Value, json};
-use std::fs;
+use std::fs::File;
+use std::io::Read;
 
 /// FileReader tool for reading file contents.
 ///
 /// Reads the contents of a file from the filesystem and returns it as a string.
-pub struct FileReader;
+/// An optional size cap can be set with `with_max_bytes`; larger files are then
+/// truncated, and the result carries a warning instead of failing.
+pub struct FileReader {
+    max_bytes: Option<usize>,
+}
 
 impl FileReader {
     pub fn new() -> Self {
-        Self
+        Self { max_bytes: None }
+    }
+
+    /// Caps the number of bytes read from a single file (no cap by default).
+    ///
+    /// Only the first `max_bytes` bytes are read, so the cap also bounds memory use.
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Map an I/O error while reading `file_path` to a tool execution error
+    fn read_error(&self, file_path: &str, e: std::io::Error) -> AgentError {
+        let reason = match e.kind() {
+            std::io::ErrorKind::NotFound => {
+                format!("File not found: {}", file_path)
+            }
+            std::io::ErrorKind::PermissionDenied => {
+                format!("Permission denied: {}", file_path)
+            }
+            _ => {
+                format!("Failed to read file {}: {}", file_path, e)
+            }
+        };
+
+        AgentError::ToolExecution {
+            tool_name: self.name().to_string(),
+            reason,
+        }
     }
 }
 
@@ -53,31 +86,55 @@ impl Tool for FileReader {
                 reason: "Missing or invalid 'file_path' parameter".to_string(),
             })?;
 
-        // Read file contents
-        let contents = fs::read_to_string(file_path).map_err(|e| {
-            let reason = match e.kind() {
-                std::io::ErrorKind::NotFound => {
-                    format!("File not found: {}", file_path)
-                }
-                std::io::ErrorKind::PermissionDenied => {
-                    format!("Permission denied: {}", file_path)
-                }
-                _ => {
-                    format!("Failed to read file {}: {}", file_path, e)
+        let mut file = File::open(file_path).map_err(|e| self.read_error(file_path, e))?;
+        let total_size = file
+            .metadata()
+            .map_err(|e| self.read_error(file_path, e))?
+            .len();
+
+        match self.max_bytes {
+            Some(max_bytes) if total_size > max_bytes as u64 => {
+                // Read only up to the cap
+                let mut bytes = Vec::with_capacity(max_bytes);
+                file.take(max_bytes as u64)
+                    .read_to_end(&mut bytes)
+                    .map_err(|e| self.read_error(file_path, e))?;
+
+                // Drop a multi-byte character cut off by the cap
+                if let Err(e) = std::str::from_utf8(&bytes) {
+                    if e.error_len().is_none() {
+                        bytes.truncate(e.valid_up_to());
+                    }
                 }
-            };
+                let contents = String::from_utf8(bytes).map_err(|e| {
+                    self.read_error(
+                        file_path,
+                        std::io::Error::new(std::io::ErrorKind::InvalidData, e),
+                    )
+                })?;
 
-            AgentError::ToolExecution {
-                tool_name: self.name().to_string(),
-                reason,
+                let size = contents.len();
+                Ok(json!({
+                    "file_path": file_path,
+                    "contents": contents,
+                    "size": size,
+                    "truncated": true,
+                    "warnings": [format!("File truncated to {} of {} bytes", size, total_size)]
+                }))
             }
-        })?;
+            _ => {
+                // Read file contents
+                let mut contents = String::new();
+                file.read_to_string(&mut contents)
+                    .map_err(|e| self.read_error(file_path, e))?;
 
-        Ok(json!({
-            "file_path": file_path,
-            "contents": contents,
-            "size": contents.len()
-        }))
+                Ok(json!({
+                    "file_path": file_path,
+                    "contents": contents,
+                    "size": contents.len()
+                }))
+            }
+        }
     }
 }
 
@@ -127,6 +184,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_file_reader_truncates_large_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all(b"0123456789").unwrap();
+        temp_file.flush().unwrap();
+
+        let reader = FileReader::new().with_max_bytes(4);
+        let params = json!({
+            "file_path": temp_file.path().to_str().unwrap()
+        });
+
+        let result = reader.execute(params).await.unwrap();
+        assert_eq!(result["contents"], "0123");
+        assert_eq!(result["size"], 4);
+        assert_eq!(result["truncated"], true);
+        assert_eq!(result["warnings"][0], "File truncated to 4 of 10 bytes");
+    }
+
+    #[tokio::test]
+    async fn test_file_reader_truncation_keeps_char_boundary() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        temp_file.write_all("abé".as_bytes()).unwrap();
+        temp_file.flush().unwrap();
+
+        // The cap falls inside the two-byte 'é'
+        let reader = FileReader::new().with_max_bytes(3);
+        let params = json!({
+            "file_path": temp_file.path().to_str().unwrap()
+        });
+
+        let result = reader.execute(params).await.unwrap();
+        assert_eq!(result["contents"], "ab");
+        assert_eq!(result["warnings"][0], "File truncated to 2 of 4 bytes");
+    }
+
+    #[tokio::test]
+    async fn test_file_reader_does_not_truncate_by_default() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        let test_content = "x".repeat(2 * 1024 * 1024);
+        temp_file.write_all(test_content.as_bytes()).unwrap();
+        temp_file.flush().unwrap();
+
+        let reader = FileReader::new();
+        let params = json!({
+            "file_path": temp_file.path().to_str().unwrap()
+        });
+
+        let result = reader.execute(params).await.unwrap();
+        assert_eq!(result["size"], test_content.len());
+        assert!(result.get("truncated").is_none());
+        assert!(result.get("warnings").is_none());
+    }
+
     #[tokio::test]
     async fn test_file_reader_empty_file() {
         // Create an empty temporary file
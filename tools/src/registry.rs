@@ -50,6 +50,18 @@ impl ToolRegistry {
             .map(|tool| ToolInfo::from_tool(tool.as_ref()))
             .collect()
     }
+
+    /// Lists the names of all registered tools.
+    ///
+    /// Cheaper than `list_tools` since it does not build parameter schemas.
+    ///
+    /// # Returns
+    /// A vector of tool names, sorted alphabetically
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.tools.keys().map(|name| name.as_str()).collect();
+        names.sort_unstable();
+        names
+    }
 }
 
 impl Default for ToolRegistry {
@@ -140,6 +152,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_registry_names() {
+        let mut registry = ToolRegistry::new();
+        assert!(registry.names().is_empty());
+
+        registry.register(Box::new(WebSearchStub::new()));
+        registry.register(Box::new(Calculator::new()));
+        registry.register(Box::new(FileReader::new()));
+
+        assert_eq!(
+            registry.names(),
+            vec!["calculator", "file_reader", "web_search"]
+        );
+    }
+
     #[test]
     fn test_registry_default() {
         let registry = ToolRegistry::default();
@@ -19,6 +19,10 @@ pub trait Tool: Send + Sync {
 
     /// Executes the tool with the given parameters.
     ///
+    /// Non-fatal conditions (e.g. truncated output or a cache hit) can be
+    /// reported by including a `"warnings"` array of strings in the result.
+    /// The executor collects these into `ExecutionResult::warnings`.
+    ///
     /// # Arguments
     /// * `params` - JSON value containing the tool parameters
     ///
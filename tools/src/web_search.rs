@@ -2,17 +2,54 @@ use crate::tool::Tool;
 use agent_core::{AgentError, Result};
 use async_trait::async_trait;
 use serde_json::{Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+/// Maximum number of queries kept in the result cache
+const MAX_CACHED_QUERIES: usize = 128;
 
 /// WebSearchStub tool that returns mock search results.
 ///
 /// This is a demonstration tool that simulates web search functionality
 /// by returning hardcoded mock results. In a production system, this would
-/// integrate with a real search API.
-pub struct WebSearchStub;
+/// integrate with a real search API. Responses are cached per query (oldest
+/// queries are evicted first), and a cache hit is reported as a warning.
+pub struct WebSearchStub {
+    cache: Mutex<QueryCache>,
+}
+
+/// Bounded cache of search responses, evicted in insertion order
+#[derive(Default)]
+struct QueryCache {
+    responses: HashMap<String, Value>,
+    order: VecDeque<String>,
+}
+
+impl QueryCache {
+    fn insert(&mut self, query: String, response: Value) {
+        if self.responses.len() >= MAX_CACHED_QUERIES {
+            if let Some(oldest) = self.order.pop_front() {
+                self.responses.remove(&oldest);
+            }
+        }
+        self.order.push_back(query.clone());
+        self.responses.insert(query, response);
+    }
+}
 
 impl WebSearchStub {
     pub fn new() -> Self {
-        Self
+        Self {
+            cache: Mutex::new(QueryCache::default()),
+        }
+    }
+
+    /// Lock the result cache, reporting a poisoned lock as a tool error
+    fn cache(&self) -> Result<MutexGuard<'_, QueryCache>> {
+        self.cache.lock().map_err(|_| AgentError::ToolExecution {
+            tool_name: self.name().to_string(),
+            reason: "Search result cache is unavailable (lock poisoned)".to_string(),
+        })
     }
 }
 
@@ -54,6 +91,13 @@ impl Tool for WebSearchStub {
                 reason: "Missing or invalid 'query' parameter".to_string(),
             })?;
 
+        // Serve repeated queries from the cache
+        if let Some(cached) = self.cache()?.responses.get(query) {
+            let mut response = cached.clone();
+            response["warnings"] = json!([format!("Served cached results for '{}'", query)]);
+            return Ok(response);
+        }
+
         // Return mock search results
         let mock_results = vec![
             json!({
@@ -73,12 +117,15 @@ impl Tool for WebSearchStub {
             }),
         ];
 
-        Ok(json!({
+        let response = json!({
             "query": query,
             "results": mock_results,
             "total_results": 3,
             "note": "These are mock results for demonstration purposes"
-        }))
+        });
+        self.cache()?.insert(query.to_string(), response.clone());
+
+        Ok(response)
     }
 }
 
@@ -123,6 +170,61 @@ mod tests {
         assert!(title.contains("test query"));
     }
 
+    #[tokio::test]
+    async fn test_web_search_cache_hit_warns() {
+        let search = WebSearchStub::new();
+        let params = json!({
+            "query": "rust programming"
+        });
+
+        let first = search.execute(params.clone()).await.unwrap();
+        assert!(first.get("warnings").is_none());
+
+        let second = search.execute(params).await.unwrap();
+        assert_eq!(second["results"], first["results"]);
+        assert_eq!(second["total_results"], first["total_results"]);
+        assert_eq!(second["note"], first["note"]);
+        assert_eq!(
+            second["warnings"][0],
+            "Served cached results for 'rust programming'"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_web_search_cache_is_bounded() {
+        let search = WebSearchStub::new();
+
+        for i in 0..=MAX_CACHED_QUERIES {
+            let params = json!({ "query": format!("query {}", i) });
+            search.execute(params).await.unwrap();
+        }
+        assert_eq!(search.cache().unwrap().responses.len(), MAX_CACHED_QUERIES);
+
+        // The oldest query was evicted, so it is no longer a cache hit
+        let result = search.execute(json!({ "query": "query 0" })).await.unwrap();
+        assert!(result.get("warnings").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_web_search_poisoned_cache_is_an_error() {
+        let search = std::sync::Arc::new(WebSearchStub::new());
+        let poisoner = search.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.cache.lock().unwrap();
+            panic!("poison the cache lock");
+        })
+        .join();
+
+        let result = search.execute(json!({ "query": "rust" })).await;
+        match result {
+            Err(AgentError::ToolExecution { tool_name, reason }) => {
+                assert_eq!(tool_name, "web_search");
+                assert!(reason.contains("poisoned"));
+            }
+            other => panic!("Expected ToolExecution error, got: {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_web_search_missing_parameter() {
         let search = WebSearchStub::new();